@@ -4,22 +4,32 @@ mod storage;
 mod types;
 mod validation;
 
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, Bytes, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, String, Symbol, Vec,
+};
 
 use storage::{DataKey, MAX_HISTORY_ITEMS};
 use types::{
-    BridgeConfig, BridgeOperationStatus, BridgeTransaction, ChainBridgeInfo,
-    MultisigBridgeRequest, PropertyMetadata, RecoveryAction,
+    BridgeConfig, BridgeOperationStatus, BridgeTransaction, ChainBridgeInfo, ChainPause,
+    ExecutionEvidence, MultisigBridgeRequest, PropertyMetadata, RecoveryAction, RequestFreeze, SignatureScheme,
+    SlashProposal,
 };
 use validation::{
-    require_admin, require_future_timestamp, require_non_zero_address, require_non_zero_u128,
-    require_non_zero_u32, require_non_zero_u64, require_not_paused, require_operator,
-    require_supported_chain, require_valid_signatures,
+    is_request_frozen, require_admin, require_chain_min_signatures, require_chain_not_paused,
+    require_fresh_heartbeat,
+    require_future_timestamp, require_non_zero_address, require_non_zero_u128,
+    require_non_zero_u32, require_non_zero_u64, require_not_frozen, require_not_paused, require_operator,
+    require_execution_evidence, require_slash_authority, require_supported_chain, require_valid_attestation,
+    require_valid_signatures,
 };
 
 const CONTRACT_VERSION: u32 = 1;
 const MAX_SUPPORTED_CHAINS: u32 = 20;
 const MAX_OPERATORS: u32 = 10;
+const MAX_BATCH_SIZE: u32 = 50;
+const MAX_PAGINATION_LIMIT: u32 = 50;
+/// Largest share of an operator's bond a single slash may take.
+const MAX_SLASH_BPS: u32 = 1_000;
 
 #[contract]
 pub struct PropertyBridge;
@@ -84,12 +94,15 @@ impl PropertyBridge {
         let mut operators = Vec::new(&env);
         operators.push_back(admin.clone());
         env.storage().instance().set(&DataKey::Operators, &operators);
+        env.storage()
+            .persistent()
+            .set(&DataKey::OperatorLastActive(admin.clone()), &env.ledger().timestamp());
 
         for chain_id in supported_chains.iter() {
             let chain_info = ChainBridgeInfo {
                 chain_id,
                 chain_name: String::from_str(&env, "Chain"),
-                bridge_contract_address: None,
+                bridge_contract_address: String::from_str(&env, ""),
                 is_active: true,
                 gas_multiplier: 100,
                 confirmation_blocks: 6,
@@ -137,14 +150,28 @@ impl PropertyBridge {
         let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
             .unwrap_or_else(|| panic!("Contract not initialized"));
 
+        let rebate_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExecutorRebateBps)
+            .unwrap_or(0);
+        let hold_fee = config.service_fee > 0 && rebate_bps > 0;
         if config.service_fee > 0 {
             use soroban_sdk::token;
             let client = token::Client::new(&env, &config.fee_token);
-            client.transfer(&caller, &config.fee_recipient, &config.service_fee);
+            // With an executor rebate configured the fee is held until execution.
+            if hold_fee {
+                client.transfer(&caller, &env.current_contract_address(), &config.service_fee);
+            } else {
+                client.transfer(&caller, &config.fee_recipient, &config.service_fee);
+            }
         }
         require_not_paused(&env);
         require_supported_chain(&config, destination_chain);
+        require_chain_not_paused(&env, destination_chain);
+        require_fresh_heartbeat(&env, destination_chain);
         require_valid_signatures(&config, required_signatures);
+        require_chain_min_signatures(&env, destination_chain, required_signatures);
 
         let mut counter: u64 = env
             .storage()
@@ -179,6 +206,11 @@ impl PropertyBridge {
         env.storage()
             .persistent()
             .set(&DataKey::Request(counter), &request);
+        if hold_fee {
+            env.storage()
+                .persistent()
+                .set(&DataKey::RequestFee(counter), &(config.service_fee, rebate_bps));
+        }
 
         env.events().publish(
             (symbol_short!("bridge"), symbol_short!("created")),
@@ -194,6 +226,7 @@ impl PropertyBridge {
         require_non_zero_u64(request_id, "request_id");
         require_operator(&env, &operator);
         require_not_paused(&env);
+        require_not_frozen(&env, request_id);
 
         let mut request: MultisigBridgeRequest = env
             .storage()
@@ -211,95 +244,105 @@ impl PropertyBridge {
             panic!("Already signed");
         }
 
-        request.signatures.push_back(operator.clone());
-
-        if !approve {
-            request.status = BridgeOperationStatus::Failed;
-        } else if request.signatures.len() >= request.required_signatures {
-            request.status = BridgeOperationStatus::Locked;
-        }
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::Request(request_id), &request);
-        
-        env.events().publish(
-            (symbol_short!("bridge"), symbol_short!("signed")),
-            (request_id, operator, approve),
-        );
+        record_signature(&env, &operator, &mut request, approve);
     }
 
-    pub fn execute_bridge(env: Env, operator: Address, request_id: u64) {
+    /// Approves several requests in one call. Requests that are missing, no
+    /// longer pending, expired, frozen or already signed by `operator` are
+    /// skipped and reported as `u32::MAX`; the others report their new
+    /// signature count.
+    pub fn sign_bridge_requests_batch(env: Env, operator: Address, request_ids: Vec<u64>) -> Vec<u32> {
         operator.require_auth();
         require_non_zero_address(&operator);
-        require_non_zero_u64(request_id, "request_id");
         require_operator(&env, &operator);
         require_not_paused(&env);
+        if request_ids.len() > MAX_BATCH_SIZE {
+            panic!("Batch too large");
+        }
 
-        let mut request: MultisigBridgeRequest = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Request(request_id))
-            .expect("Request not found");
+        let now = env.ledger().timestamp();
+        let mut results = Vec::new(&env);
+        for request_id in request_ids.iter() {
+            let request: Option<MultisigBridgeRequest> =
+                env.storage().persistent().get(&DataKey::Request(request_id));
+            let mut request = match request {
+                Some(request) => request,
+                None => {
+                    results.push_back(u32::MAX);
+                    continue;
+                }
+            };
+            let expired = matches!(request.expires_at, Some(expires_at) if now > expires_at);
+            if expired
+                || request.status != BridgeOperationStatus::Pending
+                || request.signatures.contains(operator.clone())
+                || is_request_frozen(&env, request_id)
+            {
+                results.push_back(u32::MAX);
+                continue;
+            }
 
-        if request.status != BridgeOperationStatus::Locked {
-            panic!("Request not ready");
+            record_signature(&env, &operator, &mut request, true);
+            results.push_back(request.signatures.len());
         }
+        results
+    }
+
+    pub fn execute_bridge(env: Env, operator: Address, request_id: u64) {
+        execute_request(env, operator, request_id, None);
+    }
 
-        let tx_hash = env
-            .crypto()
-            .sha256(&Bytes::from_slice(&env, &request_id.to_be_bytes()));
+    /// Executes a request to a chain that requires evidence from its side:
+    /// an attestation by the chain's attester, or an inclusion proof against
+    /// one of the chain's checkpoints.
+    pub fn execute_bridge_with_evidence(
+        env: Env,
+        operator: Address,
+        request_id: u64,
+        evidence: ExecutionEvidence,
+    ) {
+        execute_request(env, operator, request_id, Some(evidence));
+    }
 
-        let mut tx_counter: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TxCounter)
-            .unwrap_or(0);
-        tx_counter += 1;
-        env.storage().instance().set(&DataKey::TxCounter, &tx_counter);
-
-        let sender = request.sender.clone();
-
-        let transaction = BridgeTransaction {
-            transaction_id: tx_counter,
-            token_id: request.token_id,
-            source_chain: request.source_chain,
-            destination_chain: request.destination_chain,
-            sender: sender.clone(),
-            recipient: request.recipient.clone(),
-            transaction_hash: tx_hash.clone(),
-            timestamp: env.ledger().timestamp(),
-            gas_used: 0,
-            status: BridgeOperationStatus::InTransit,
-            metadata: request.metadata.clone(),
-        };
+    /// Marks pending or locked requests past their expiry as `Expired` so
+    /// they can be recovered. Callable by anyone; requests that are not
+    /// expired or already settled are skipped. Returns the number expired.
+    pub fn expire_stale_requests(env: Env, request_ids: Vec<u64>) -> u32 {
+        if request_ids.len() > MAX_BATCH_SIZE {
+            panic!("Batch too large");
+        }
 
-        request.status = BridgeOperationStatus::Completed;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Request(request_id), &request);
-        env.storage()
-            .persistent()
-            .set(&DataKey::VerifiedTx(tx_hash.clone()), &true);
+        let now = env.ledger().timestamp();
+        let mut expired: u32 = 0;
+        for request_id in request_ids.iter() {
+            let request: Option<MultisigBridgeRequest> =
+                env.storage().persistent().get(&DataKey::Request(request_id));
+            let mut request = match request {
+                Some(request) => request,
+                None => continue,
+            };
+            let open = matches!(
+                request.status,
+                BridgeOperationStatus::Pending | BridgeOperationStatus::Locked
+            );
+            let past_expiry = matches!(request.expires_at, Some(expires_at) if now > expires_at);
+            // Frozen requests wait for the investigation instead of expiring
+            if !open || !past_expiry || is_request_frozen(&env, request_id) {
+                continue;
+            }
 
-        let mut history: Vec<BridgeTransaction> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::History(sender.clone()))
-            .unwrap_or(Vec::new(&env));
+            request.status = BridgeOperationStatus::Expired;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Request(request_id), &request);
+            expired += 1;
 
-        if history.len() >= MAX_HISTORY_ITEMS {
-            history.remove(0);
+            env.events().publish(
+                (symbol_short!("bridge"), symbol_short!("expired")),
+                request_id,
+            );
         }
-        history.push_back(transaction);
-        env.storage()
-            .persistent()
-            .set(&DataKey::History(sender), &history);
-
-        env.events().publish(
-            (symbol_short!("bridge"), symbol_short!("executed")),
-            (request_id, tx_hash),
-        );
+        expired
     }
 
     pub fn recover_failed_bridge(
@@ -336,6 +379,22 @@ impl PropertyBridge {
             | RecoveryAction::UnlockToken
             | RecoveryAction::RefundGas => {
                 request.status = BridgeOperationStatus::Failed;
+
+                // A request that will never execute forwards its held fee to the fee recipient.
+                if let Some((fee, _rebate_bps)) = env
+                    .storage()
+                    .persistent()
+                    .get::<DataKey, (i128, u32)>(&DataKey::RequestFee(request_id))
+                {
+                    env.storage().persistent().remove(&DataKey::RequestFee(request_id));
+                    let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+                        .unwrap_or_else(|| panic!("Contract not initialized"));
+                    soroban_sdk::token::Client::new(&env, &config.fee_token).transfer(
+                        &env.current_contract_address(),
+                        &config.fee_recipient,
+                        &fee,
+                    );
+                }
             }
         }
 
@@ -365,114 +424,1269 @@ impl PropertyBridge {
         );
     }
 
-    pub fn add_operator(env: Env, admin: Address, operator: Address) {
+    /// Stops new requests towards `chain_id` without pausing the rest of
+    /// the bridge. Requests already in flight are unaffected.
+    pub fn pause_chain(env: Env, admin: Address, chain_id: u32, reason: Symbol) {
         admin.require_auth();
         require_non_zero_address(&admin);
-        require_non_zero_address(&operator);
         require_admin(&env, &admin);
+        require_chain_not_paused(&env, chain_id);
 
-        let mut operators: Vec<Address> =
-            env.storage().instance().get(&DataKey::Operators)
-                .unwrap_or_else(|| panic!("Contract not initialized"));
-        
-        if operators.len() >= MAX_OPERATORS {
-            panic!("Too many operators");
-        }
+        let pause = ChainPause {
+            paused_by: admin.clone(),
+            reason: reason.clone(),
+            paused_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&DataKey::ChainPause(chain_id), &pause);
 
-        if !operators.contains(operator.clone()) {
-            operators.push_back(operator.clone());
-            env.storage().instance().set(&DataKey::Operators, &operators);
-            
-            env.events().publish(
-                (symbol_short!("bridge"), symbol_short!("opadd")),
-                operator,
-            );
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("chn_pause")),
+            (chain_id, admin, reason),
+        );
+    }
+
+    /// Lifts a pause placed by `pause_chain`. The chain's `is_active` flag is
+    /// left as it was; pausing never changes it.
+    pub fn unpause_chain(env: Env, admin: Address, chain_id: u32) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+
+        if !env.storage().persistent().has(&DataKey::ChainPause(chain_id)) {
+            panic!("Chain not paused");
         }
+        env.storage().persistent().remove(&DataKey::ChainPause(chain_id));
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("chn_resum")),
+            (chain_id, admin),
+        );
     }
 
-    pub fn remove_operator(env: Env, admin: Address, operator: Address) {
+    /// Freezes a single request: it cannot be signed, executed or expired
+    /// until the freeze is lifted.
+    pub fn freeze_request(env: Env, admin: Address, request_id: u64, reason: String) {
         admin.require_auth();
         require_non_zero_address(&admin);
-        require_non_zero_address(&operator);
         require_admin(&env, &admin);
+        require_non_zero_u64(request_id, "request_id");
 
-        let operators: Vec<Address> =
-            env.storage().instance().get(&DataKey::Operators)
-                .unwrap_or_else(|| panic!("Contract not initialized"));
-        
-        let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
-            .unwrap_or_else(|| panic!("Contract not initialized"));
+        if !env.storage().persistent().has(&DataKey::Request(request_id)) {
+            panic!("Request not found");
+        }
+        if is_request_frozen(&env, request_id) {
+            panic!("Request frozen");
+        }
 
-        if operators.len() <= config.min_signatures_required {
-            panic!("Cannot remove operator: minimum signature requirement would not be met");
+        let mut freezes = request_freezes(&env, request_id);
+        if freezes.len() >= MAX_HISTORY_ITEMS {
+            freezes.pop_front();
         }
+        freezes.push_back(RequestFreeze {
+            frozen_by: admin.clone(),
+            reason: reason.clone(),
+            frozen_at: env.ledger().timestamp(),
+            released_at: None,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::RequestFreezes(request_id), &freezes);
 
-        let mut new_operators = Vec::new(&env);
-        for op in operators.iter() {
-            if op != operator {
-                new_operators.push_back(op);
-            }
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("freeze")),
+            (request_id, admin, reason),
+        );
+    }
+
+    pub fn unfreeze_request(env: Env, admin: Address, request_id: u64) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+
+        if !is_request_frozen(&env, request_id) {
+            panic!("Request not frozen");
         }
-        env.storage().instance().set(&DataKey::Operators, &new_operators);
-        
+        let mut freezes = request_freezes(&env, request_id);
+        let last = freezes.len() - 1;
+        let mut freeze = freezes.get_unchecked(last);
+        freeze.released_at = Some(env.ledger().timestamp());
+        freezes.set(last, freeze);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RequestFreezes(request_id), &freezes);
+
         env.events().publish(
-            (symbol_short!("bridge"), symbol_short!("oprm")),
-            operator,
+            (symbol_short!("bridge"), symbol_short!("unfreeze")),
+            (request_id, admin),
         );
     }
-}
 
-#[contractimpl]
-impl PropertyBridge {
-    pub fn version(env: Env) -> u32 {
+    /// Sets the share of the service fee, in basis points, rebated to the
+    /// operator that executes a request. Zero disables the rebate.
+    pub fn set_executor_rebate(env: Env, admin: Address, rebate_bps: u32) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+        if rebate_bps > 10_000 {
+            panic!("Rebate cannot exceed 10000 basis points");
+        }
+
+        env.storage().instance().set(&DataKey::ExecutorRebateBps, &rebate_bps);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("rebatecfg")),
+            rebate_bps,
+        );
+    }
+
+    /// Raises the minimum signatures required for requests to `chain_id`
+    /// above the global minimum. Chains without an override keep the global value.
+    pub fn set_chain_min_signatures(env: Env, admin: Address, chain_id: u32, min_signatures: u32) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+        require_non_zero_u32(min_signatures, "min_signatures");
+
+        let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+        require_supported_chain(&config, chain_id);
+        if min_signatures > config.max_signatures_required {
+            panic!("min_signatures cannot exceed max_signatures");
+        }
+
         env.storage()
             .instance()
-            .get(&DataKey::Version)
-            .unwrap_or(CONTRACT_VERSION)
+            .set(&DataKey::ChainMinSignatures(chain_id), &min_signatures);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("chainsigs")),
+            (chain_id, min_signatures),
+        );
     }
 
-    pub fn get_config(env: Env) -> BridgeConfig {
+    /// Sets the signature scheme attesters on `chain_id` sign with. The
+    /// chain's attester is cleared and must be set again for the new scheme.
+    pub fn set_chain_signature_scheme(
+        env: Env,
+        admin: Address,
+        chain_id: u32,
+        scheme: SignatureScheme,
+    ) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+
+        let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+        require_supported_chain(&config, chain_id);
+
         env.storage()
             .instance()
-            .get(&DataKey::Config)
-            .expect("Contract not initialized")
+            .set(&DataKey::ChainSignatureScheme(chain_id), &scheme);
+        env.storage().instance().remove(&DataKey::ChainAttester(chain_id));
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("sigscheme")),
+            (chain_id, scheme),
+        );
     }
 
-    pub fn get_admin(env: Env) -> Address {
+    /// Sets the attester on `chain_id` whose signature a request to that chain
+    /// needs before it executes. The key must fit the chain's signature scheme.
+    pub fn set_chain_attester(env: Env, admin: Address, chain_id: u32, public_key: Bytes) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+
+        let scheme: SignatureScheme = env
+            .storage()
+            .instance()
+            .get(&DataKey::ChainSignatureScheme(chain_id))
+            .unwrap_or_else(|| panic!("Unsupported signature scheme"));
+        let key_len = match scheme {
+            SignatureScheme::Ed25519 => 32,
+            SignatureScheme::Secp256k1 => 65,
+        };
+        if public_key.len() != key_len {
+            panic!("Signature scheme mismatch");
+        }
+
         env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Contract not initialized")
-    }
+            .set(&DataKey::ChainAttester(chain_id), &public_key);
 
-    pub fn get_request(env: Env, request_id: u64) -> Option<MultisigBridgeRequest> {
-        env.storage().persistent().get(&DataKey::Request(request_id))
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("attester")),
+            (chain_id, public_key),
+        );
     }
 
-    pub fn get_history(env: Env, address: Address) -> Vec<BridgeTransaction> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::History(address))
-            .unwrap_or(Vec::new(&env))
+    /// Checks an attestation from `chain_id` against that chain's signature
+    /// scheme. Panics if the chain has no scheme, the key does not fit the
+    /// scheme, or the signature does not verify.
+    pub fn verify_chain_attestation(
+        env: Env,
+        chain_id: u32,
+        public_key: Bytes,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: u32,
+    ) {
+        require_valid_attestation(&env, chain_id, &public_key, &message, &signature, recovery_id);
     }
 
-    pub fn get_chain_info(env: Env, chain_id: u32) -> Option<ChainBridgeInfo> {
-        env.storage().persistent().get(&DataKey::ChainInfo(chain_id))
+    /// Records that `operator` has observed `chain_id` to be live.
+    pub fn record_chain_heartbeat(env: Env, operator: Address, chain_id: u32) {
+        operator.require_auth();
+        require_non_zero_address(&operator);
+        require_operator(&env, &operator);
+
+        let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+        require_supported_chain(&config, chain_id);
+
+        let now = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::ChainHeartbeat(chain_id), &now);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("heartbeat")),
+            (chain_id, operator, now),
+        );
     }
 
-    pub fn is_operator(env: Env, address: Address) -> bool {
-        let operators: Vec<Address> = env
+    /// Votes for `state_root` as the state of `chain_id` at `block_height`.
+    /// Each operator votes once per block. The root is committed once the
+    /// operators backing it reach the chain's signature threshold, and cannot
+    /// change afterwards; the votes for that block are then cleared.
+    pub fn submit_checkpoint(
+        env: Env,
+        operator: Address,
+        chain_id: u32,
+        block_height: u64,
+        state_root: BytesN<32>,
+    ) -> bool {
+        operator.require_auth();
+        require_non_zero_address(&operator);
+        require_operator(&env, &operator);
+        require_not_paused(&env);
+
+        let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+        require_supported_chain(&config, chain_id);
+        if env.storage().persistent().has(&DataKey::Checkpoint(chain_id, block_height)) {
+            panic!("Checkpoint already committed");
+        }
+
+        let roots_key = DataKey::CheckpointRoots(chain_id, block_height);
+        let mut roots: Vec<BytesN<32>> = env
             .storage()
-            .instance()
-            .get(&DataKey::Operators)
+            .persistent()
+            .get(&roots_key)
             .unwrap_or(Vec::new(&env));
-        operators.contains(address)
-    }
+        for root in roots.iter() {
+            let root_voters: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::CheckpointVotes(chain_id, block_height, root))
+                .unwrap_or(Vec::new(&env));
+            if root_voters.contains(operator.clone()) {
+                panic!("Already signed");
+            }
+        }
+        if !roots.contains(state_root.clone()) {
+            roots.push_back(state_root.clone());
+            env.storage().persistent().set(&roots_key, &roots);
+        }
 
-    pub fn get_nonce(env: Env, address: Address) -> u64 {
-        env.storage()
+        let votes_key = DataKey::CheckpointVotes(chain_id, block_height, state_root.clone());
+        let mut voters: Vec<Address> = env
+            .storage()
             .persistent()
-            .get(&DataKey::Nonce(address))
-            .unwrap_or(0)
+            .get(&votes_key)
+            .unwrap_or(Vec::new(&env));
+        voters.push_back(operator.clone());
+        env.storage().persistent().set(&votes_key, &voters);
+
+        let required = Self::get_chain_min_signatures(env.clone(), chain_id);
+        let committed = signature_weight(&env, &voters, required) >= required;
+        if committed {
+            for root in roots.iter() {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::CheckpointVotes(chain_id, block_height, root));
+            }
+            env.storage().persistent().remove(&roots_key);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Checkpoint(chain_id, block_height), &state_root);
+            let latest: u64 = env
+                .storage()
+                .instance()
+                .get(&DataKey::LatestCheckpoint(chain_id))
+                .unwrap_or(0);
+            if block_height >= latest {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::LatestCheckpoint(chain_id), &block_height);
+            }
+
+            env.events().publish(
+                (symbol_short!("bridge"), symbol_short!("chkpoint")),
+                (chain_id, block_height, state_root),
+            );
+        }
+        committed
     }
-}
+
+    /// Rejects new requests to chains whose last heartbeat is older than
+    /// `max_age` seconds. Zero disables the check.
+    pub fn set_max_heartbeat_age(env: Env, admin: Address, max_age: u64) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+
+        env.storage().instance().set(&DataKey::MaxHeartbeatAge, &max_age);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("hbcfg")),
+            max_age,
+        );
+    }
+
+    /// Sets how much `operator`'s signature counts towards a request's
+    /// `required_signatures`. Operators default to a weight of 1.
+    pub fn set_operator_weight(env: Env, admin: Address, operator: Address, weight: u32) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_non_zero_address(&operator);
+        require_admin(&env, &admin);
+        require_operator(&env, &operator);
+        require_non_zero_u32(weight, "weight");
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::OperatorWeight(operator.clone()), &weight);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("opweight")),
+            (operator, weight),
+        );
+    }
+
+    /// Caps how much of a request's signature threshold any one operator can
+    /// cover, in basis points, so a heavily weighted operator still needs
+    /// others to sign. Zero disables the cap.
+    pub fn set_max_single_operator_bps(env: Env, admin: Address, max_bps: u32) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+        if max_bps > 10_000 {
+            panic!("Cap cannot exceed 10000 basis points");
+        }
+
+        env.storage().instance().set(&DataKey::MaxSingleOperatorBps, &max_bps);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("opcap")),
+            max_bps,
+        );
+    }
+
+    /// Tags `operator` with the group that runs it, so several keys held by
+    /// one entity count once towards `set_min_distinct_groups`.
+    pub fn set_operator_group(env: Env, admin: Address, operator: Address, group: u32) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_non_zero_address(&operator);
+        require_admin(&env, &admin);
+        require_operator(&env, &operator);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::OperatorGroup(operator.clone()), &group);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("opgroup")),
+            (operator, group),
+        );
+    }
+
+    /// Requires approvals from at least `min_groups` distinct operator groups
+    /// before a request locks. Zero disables the check.
+    pub fn set_min_distinct_groups(env: Env, admin: Address, min_groups: u32) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+
+        let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+        if min_groups > config.max_signatures_required {
+            panic!("min_groups cannot exceed max_signatures");
+        }
+
+        env.storage().instance().set(&DataKey::MinDistinctGroups, &min_groups);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("mingroups")),
+            min_groups,
+        );
+    }
+
+    pub fn add_operator(env: Env, admin: Address, operator: Address) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_non_zero_address(&operator);
+        require_admin(&env, &admin);
+
+        let mut operators: Vec<Address> =
+            env.storage().instance().get(&DataKey::Operators)
+                .unwrap_or_else(|| panic!("Contract not initialized"));
+        
+        if operators.len() >= MAX_OPERATORS {
+            panic!("Too many operators");
+        }
+
+        if !operators.contains(operator.clone()) {
+            operators.push_back(operator.clone());
+            env.storage().instance().set(&DataKey::Operators, &operators);
+            // a re-added operator's bond is locked again
+            env.storage().persistent().remove(&DataKey::UnbondingSince(operator.clone()));
+            env.storage()
+                .persistent()
+                .set(&DataKey::OperatorLastActive(operator.clone()), &env.ledger().timestamp());
+            
+            env.events().publish(
+                (symbol_short!("bridge"), symbol_short!("opadd")),
+                operator,
+            );
+        }
+    }
+
+    pub fn remove_operator(env: Env, admin: Address, operator: Address) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_non_zero_address(&operator);
+        require_admin(&env, &admin);
+
+        let operators: Vec<Address> =
+            env.storage().instance().get(&DataKey::Operators)
+                .unwrap_or_else(|| panic!("Contract not initialized"));
+        
+        let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+
+        if operators.len() <= config.min_signatures_required {
+            panic!("Cannot remove operator: minimum signature requirement would not be met");
+        }
+
+        let mut new_operators = Vec::new(&env);
+        for op in operators.iter() {
+            if op != operator {
+                new_operators.push_back(op);
+            }
+        }
+        env.storage().instance().set(&DataKey::Operators, &new_operators);
+        forget_operator(&env, &operator);
+        
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("oprm")),
+            operator,
+        );
+    }
+
+    /// Removes operators that have not signed any request since `inactive_since`,
+    /// slashing the configured inactivity share of each one's bond. The rest
+    /// of the bond stays locked under the usual unbonding delay.
+    ///
+    /// Removal stops once the operator set would drop below
+    /// `min_signatures_required`, mirroring the guard in `remove_operator`.
+    /// Returns the operators that were removed.
+    pub fn penalize_inactive_operators(env: Env, admin: Address, inactive_since: u64) -> Vec<Address> {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+
+        let operators: Vec<Address> =
+            env.storage().instance().get(&DataKey::Operators)
+                .unwrap_or_else(|| panic!("Contract not initialized"));
+
+        let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+
+        let inactivity_slash_bps: u32 = env.storage().instance().get(&DataKey::InactivitySlashBps).unwrap_or(0);
+        let mut remaining = operators.len();
+        let mut kept = Vec::new(&env);
+        let mut removed = Vec::new(&env);
+        for op in operators.iter() {
+            let last_active: u64 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::OperatorLastActive(op.clone()))
+                .unwrap_or(0);
+            if last_active < inactive_since && remaining > config.min_signatures_required {
+                remaining -= 1;
+                removed.push_back(op.clone());
+                let slashed = slash_bond(&env, &op, inactivity_slash_bps);
+                forget_operator(&env, &op);
+
+                env.events().publish(
+                    (symbol_short!("bridge"), symbol_short!("inactive")),
+                    (op.clone(), last_active, slashed),
+                );
+                env.events().publish(
+                    (symbol_short!("bridge"), symbol_short!("oprm")),
+                    op,
+                );
+            } else {
+                kept.push_back(op);
+            }
+        }
+        env.storage().instance().set(&DataKey::Operators, &kept);
+
+        removed
+    }
+
+    /// Bonds `amount` of the fee token as stake that can be slashed if the
+    /// operator misbehaves.
+    pub fn bond_operator(env: Env, operator: Address, amount: i128) {
+        operator.require_auth();
+        require_non_zero_address(&operator);
+        require_operator(&env, &operator);
+        if amount <= 0 {
+            panic!("amount must be greater than zero");
+        }
+
+        let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+        soroban_sdk::token::Client::new(&env, &config.fee_token).transfer(
+            &operator,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let bond = operator_bond(&env, &operator) + amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::OperatorBond(operator.clone()), &bond);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("bond")),
+            (operator, amount, bond),
+        );
+    }
+
+    /// Returns `amount` of an operator's bond. Allowed only after the
+    /// operator has been removed and a full slash review period has passed
+    /// since, so misbehaviour reported up to removal can still be slashed,
+    /// and only while no slash against the operator is pending.
+    pub fn withdraw_bond(env: Env, operator: Address, amount: i128) {
+        operator.require_auth();
+        require_non_zero_address(&operator);
+        if amount <= 0 {
+            panic!("amount must be greater than zero");
+        }
+        let unbonding_since: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UnbondingSince(operator.clone()))
+            .unwrap_or_else(|| panic!("Operator must be removed before unbonding"));
+        let review_period: u64 = env.storage().instance().get(&DataKey::SlashReviewPeriod).unwrap_or(0);
+        if env.ledger().timestamp() < unbonding_since + review_period {
+            panic!("Bond still unbonding");
+        }
+        if pending_slashes(&env, &operator) > 0 {
+            panic!("Slash pending");
+        }
+        let bond = operator_bond(&env, &operator);
+        if amount > bond {
+            panic!("Insufficient bond");
+        }
+
+        pay_out_bond(&env, &operator, amount, bond - amount);
+    }
+
+    pub fn set_slash_review_period(env: Env, admin: Address, seconds: u64) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+
+        env.storage().instance().set(&DataKey::SlashReviewPeriod, &seconds);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("slashcfg")),
+            seconds,
+        );
+    }
+
+    /// Sets the share of its bond, at most 10%, that an operator loses when
+    /// `penalize_inactive_operators` removes it. Zero disables the slash.
+    pub fn set_inactivity_slash_bps(env: Env, admin: Address, percent_bps: u32) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_admin(&env, &admin);
+        if percent_bps > MAX_SLASH_BPS {
+            panic!("Slash percent too high");
+        }
+
+        env.storage().instance().set(&DataKey::InactivitySlashBps, &percent_bps);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("inactslsh")),
+            percent_bps,
+        );
+    }
+
+    /// Lets `governance` propose, execute and cancel slashes alongside the
+    /// admin, so slashing can follow a governance vote.
+    pub fn set_slash_governance(env: Env, admin: Address, governance: Address) {
+        admin.require_auth();
+        require_non_zero_address(&admin);
+        require_non_zero_address(&governance);
+        require_admin(&env, &admin);
+
+        env.storage().instance().set(&DataKey::SlashGovernance, &governance);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("slashgov")),
+            governance,
+        );
+    }
+
+    /// Proposes slashing `percent_bps` of an operator's bond, at most 10%.
+    /// `proposer` is the admin or the slash governance.
+    pub fn propose_slash(
+        env: Env,
+        proposer: Address,
+        operator: Address,
+        percent_bps: u32,
+        evidence_hash: BytesN<32>,
+    ) -> u64 {
+        proposer.require_auth();
+        require_non_zero_address(&proposer);
+        require_slash_authority(&env, &proposer);
+        require_non_zero_u32(percent_bps, "percent_bps");
+
+        if percent_bps > MAX_SLASH_BPS {
+            panic!("Slash percent too high");
+        }
+        if operator_bond(&env, &operator) == 0 {
+            panic!("Operator not bonded");
+        }
+
+        let slash_id: u64 = env.storage().instance().get(&DataKey::SlashCounter).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::SlashCounter, &slash_id);
+
+        let proposal = SlashProposal {
+            operator: operator.clone(),
+            percent_bps,
+            evidence_hash,
+            proposed_by: proposer,
+            proposed_at: env.ledger().timestamp(),
+            executed: false,
+            cancelled: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::SlashProposal(slash_id), &proposal);
+        set_pending_slashes(&env, &operator, pending_slashes(&env, &operator) + 1);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("slash_prp")),
+            (slash_id, operator, percent_bps),
+        );
+
+        slash_id
+    }
+
+    /// Drops a pending slash, e.g. after the operator disputes the evidence.
+    pub fn cancel_slash(env: Env, caller: Address, slash_id: u64) {
+        caller.require_auth();
+        require_non_zero_address(&caller);
+        require_slash_authority(&env, &caller);
+
+        let mut proposal = pending_slash_proposal(&env, slash_id);
+        proposal.cancelled = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::SlashProposal(slash_id), &proposal);
+        set_pending_slashes(&env, &proposal.operator, pending_slashes(&env, &proposal.operator) - 1);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("slash_cnl")),
+            (slash_id, proposal.operator, caller),
+        );
+    }
+
+    /// Executes a slash once its review period has passed, sending the
+    /// slashed stake to the fee recipient. Returns the amount slashed.
+    pub fn execute_slash(env: Env, caller: Address, slash_id: u64) -> i128 {
+        caller.require_auth();
+        require_non_zero_address(&caller);
+        require_slash_authority(&env, &caller);
+
+        let mut proposal = pending_slash_proposal(&env, slash_id);
+        let review_period: u64 = env.storage().instance().get(&DataKey::SlashReviewPeriod).unwrap_or(0);
+        if env.ledger().timestamp() < proposal.proposed_at + review_period {
+            panic!("Slash review period active");
+        }
+
+        let amount = slash_bond(&env, &proposal.operator, proposal.percent_bps);
+
+        proposal.executed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::SlashProposal(slash_id), &proposal);
+        set_pending_slashes(&env, &proposal.operator, pending_slashes(&env, &proposal.operator) - 1);
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("slashed")),
+            (slash_id, proposal.operator, amount),
+        );
+
+        amount
+    }
+}
+
+#[contractimpl]
+impl PropertyBridge {
+    pub fn version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Version)
+            .unwrap_or(CONTRACT_VERSION)
+    }
+
+    pub fn get_config(env: Env) -> BridgeConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .expect("Contract not initialized")
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Contract not initialized")
+    }
+
+    pub fn get_request(env: Env, request_id: u64) -> Option<MultisigBridgeRequest> {
+        env.storage().persistent().get(&DataKey::Request(request_id))
+    }
+
+    pub fn get_history(env: Env, address: Address) -> Vec<BridgeTransaction> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::History(address))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_chain_info(env: Env, chain_id: u32) -> Option<ChainBridgeInfo> {
+        env.storage().persistent().get(&DataKey::ChainInfo(chain_id))
+    }
+
+    pub fn is_operator(env: Env, address: Address) -> bool {
+        let operators: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Operators)
+            .unwrap_or(Vec::new(&env));
+        operators.contains(address)
+    }
+
+    /// Returns up to `limit` operators (capped at 50) starting at index
+    /// `start`, together with the total operator count. Out-of-range pages
+    /// are empty.
+    pub fn get_operators_paginated(env: Env, start: u32, limit: u32) -> (Vec<Address>, u32) {
+        let operators: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Operators)
+            .unwrap_or(Vec::new(&env));
+        let total = operators.len();
+        let end = start.saturating_add(limit.min(MAX_PAGINATION_LIMIT)).min(total);
+
+        let mut page = Vec::new(&env);
+        for i in start..end {
+            page.push_back(operators.get_unchecked(i));
+        }
+        (page, total)
+    }
+
+    /// Returns operators whose last signature (or addition, if they never
+    /// signed) is older than `cutoff`.
+    pub fn get_inactive_operators(env: Env, cutoff: u64) -> Vec<Address> {
+        let operators: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Operators)
+            .unwrap_or(Vec::new(&env));
+
+        let mut inactive = Vec::new(&env);
+        for op in operators.iter() {
+            let last_active: u64 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::OperatorLastActive(op.clone()))
+                .unwrap_or(0);
+            if last_active < cutoff {
+                inactive.push_back(op);
+            }
+        }
+        inactive
+    }
+
+    pub fn get_operator_last_active(env: Env, operator: Address) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OperatorLastActive(operator))
+    }
+
+    /// Fee-token stake `operator` currently has bonded; zero if none.
+    pub fn get_operator_bond(env: Env, operator: Address) -> i128 {
+        operator_bond(&env, &operator)
+    }
+
+    /// When the operator's bond started unbonding, or `None` while it is
+    /// still an operator.
+    pub fn get_unbonding_since(env: Env, operator: Address) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::UnbondingSince(operator))
+    }
+
+    /// Slash proposal `slash_id`, including whether it has been executed.
+    pub fn get_slash_proposal(env: Env, slash_id: u64) -> Option<SlashProposal> {
+        env.storage().persistent().get(&DataKey::SlashProposal(slash_id))
+    }
+
+    /// Active pause on `chain_id`, or `None` if requests to it are open.
+    pub fn get_chain_pause(env: Env, chain_id: u32) -> Option<ChainPause> {
+        env.storage().persistent().get(&DataKey::ChainPause(chain_id))
+    }
+
+    /// Minimum signatures required for requests to `chain_id`, using the
+    /// chain override if one is set.
+    pub fn get_chain_min_signatures(env: Env, chain_id: u32) -> u32 {
+        let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+        env.storage()
+            .instance()
+            .get(&DataKey::ChainMinSignatures(chain_id))
+            .unwrap_or(config.min_signatures_required)
+    }
+
+    pub fn get_chain_signature_scheme(env: Env, chain_id: u32) -> Option<SignatureScheme> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ChainSignatureScheme(chain_id))
+    }
+
+    pub fn get_chain_attester(env: Env, chain_id: u32) -> Option<Bytes> {
+        env.storage().instance().get(&DataKey::ChainAttester(chain_id))
+    }
+
+    /// Highest block of `chain_id` with a committed checkpoint.
+    pub fn get_latest_checkpoint(env: Env, chain_id: u32) -> Option<u64> {
+        env.storage().instance().get(&DataKey::LatestCheckpoint(chain_id))
+    }
+
+    pub fn get_checkpoint(env: Env, chain_id: u32, block_height: u64) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Checkpoint(chain_id, block_height))
+    }
+
+    /// Checks a Merkle proof of `leaf` against the state root committed for
+    /// `chain_id` at `block_height`. Sibling pairs are hashed in sorted order.
+    pub fn verify_checkpoint_proof(
+        env: Env,
+        chain_id: u32,
+        block_height: u64,
+        leaf: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+    ) -> bool {
+        let root: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Checkpoint(chain_id, block_height))
+            .unwrap_or_else(|| panic!("Checkpoint not committed"));
+        merkle_root(&env, leaf, &proof) == root
+    }
+
+    pub fn get_chain_last_heartbeat(env: Env, chain_id: u32) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::ChainHeartbeat(chain_id))
+    }
+
+    pub fn get_operator_weight(env: Env, operator: Address) -> u32 {
+        operator_weight(&env, &operator)
+    }
+
+    /// Summed weight of the operators that have signed `request_id`, after
+    /// the per-operator cap.
+    pub fn get_signature_weight(env: Env, request_id: u64) -> u32 {
+        let request: MultisigBridgeRequest = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Request(request_id))
+            .expect("Request not found");
+        signature_weight(&env, &request.signatures, request.required_signatures)
+    }
+
+    pub fn get_operator_group(env: Env, operator: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OperatorGroup(operator))
+            .unwrap_or(0)
+    }
+
+    pub fn is_request_frozen(env: Env, request_id: u64) -> bool {
+        is_request_frozen(&env, request_id)
+    }
+
+    /// Freezes placed on a request, for audit.
+    pub fn get_request_freezes(env: Env, request_id: u64) -> Vec<RequestFreeze> {
+        request_freezes(&env, request_id)
+    }
+
+    /// Service fee held for a request and the executor rebate, in basis
+    /// points, it will be split at.
+    pub fn get_request_fee(env: Env, request_id: u64) -> Option<(i128, u32)> {
+        env.storage().persistent().get(&DataKey::RequestFee(request_id))
+    }
+
+    pub fn get_nonce(env: Env, address: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Nonce(address))
+            .unwrap_or(0)
+    }
+}
+
+/// Adds `operator`'s signature to `request`, updates its status and persists it.
+fn record_signature(env: &Env, operator: &Address, request: &mut MultisigBridgeRequest, approve: bool) {
+    request.signatures.push_back(operator.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::OperatorLastActive(operator.clone()), &env.ledger().timestamp());
+
+    if !approve {
+        request.status = BridgeOperationStatus::Failed;
+    } else if signature_weight(env, &request.signatures, request.required_signatures)
+        >= request.required_signatures
+        && distinct_operator_groups(env, &request.signatures) >= min_distinct_groups(env)
+    {
+        request.status = BridgeOperationStatus::Locked;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Request(request.request_id), request);
+
+    env.events().publish(
+        (symbol_short!("bridge"), symbol_short!("signed")),
+        (request.request_id, operator.clone(), approve),
+    );
+}
+
+fn operator_weight(env: &Env, operator: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OperatorWeight(operator.clone()))
+        .unwrap_or(1)
+}
+
+/// Summed signer weight towards `required_signatures`, with each signer's
+/// contribution capped by `MaxSingleOperatorBps` of that threshold.
+fn signature_weight(env: &Env, signers: &Vec<Address>, required_signatures: u32) -> u32 {
+    let cap_bps: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::MaxSingleOperatorBps)
+        .unwrap_or(0);
+    let cap = if cap_bps == 0 {
+        u32::MAX
+    } else {
+        (required_signatures as u64 * cap_bps as u64 / 10_000).max(1) as u32
+    };
+
+    let mut total: u32 = 0;
+    for signer in signers.iter() {
+        total = total.saturating_add(operator_weight(env, &signer).min(cap));
+    }
+    total
+}
+
+fn min_distinct_groups(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinDistinctGroups)
+        .unwrap_or(0)
+}
+
+fn distinct_operator_groups(env: &Env, signers: &Vec<Address>) -> u32 {
+    let mut groups: Vec<u32> = Vec::new(env);
+    for signer in signers.iter() {
+        let group: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::OperatorGroup(signer))
+            .unwrap_or(0);
+        if !groups.contains(group) {
+            groups.push_back(group);
+        }
+    }
+    groups.len()
+}
+
+fn merkle_root(env: &Env, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> BytesN<32> {
+    let mut node = leaf;
+    for sibling in proof.iter() {
+        let (left, right) = if node <= sibling { (node, sibling) } else { (sibling, node) };
+        let mut pair = Bytes::from_array(env, &left.to_array());
+        pair.append(&Bytes::from_array(env, &right.to_array()));
+        node = env.crypto().sha256(&pair);
+    }
+    node
+}
+
+/// Panics unless `evidence` proves `tx_hash` is included in a committed
+/// checkpoint of `chain_id`. Chains without checkpoints need no proof.
+fn require_checkpoint_inclusion(
+    env: &Env,
+    chain_id: u32,
+    tx_hash: &BytesN<32>,
+    evidence: &Option<ExecutionEvidence>,
+) {
+    if !env.storage().instance().has(&DataKey::LatestCheckpoint(chain_id)) {
+        return;
+    }
+    let evidence = evidence.as_ref().unwrap_or_else(|| panic!("Checkpoint proof required"));
+    let root: BytesN<32> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Checkpoint(chain_id, evidence.block_height))
+        .unwrap_or_else(|| panic!("Checkpoint not committed"));
+    if merkle_root(env, tx_hash.clone(), &evidence.proof) != root {
+        panic!("Invalid checkpoint proof");
+    }
+}
+
+fn request_freezes(env: &Env, request_id: u64) -> Vec<RequestFreeze> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RequestFreezes(request_id))
+        .unwrap_or(Vec::new(env))
+}
+
+fn operator_bond(env: &Env, operator: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OperatorBond(operator.clone()))
+        .unwrap_or(0)
+}
+
+fn pending_slashes(env: &Env, operator: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingSlashes(operator.clone()))
+        .unwrap_or(0)
+}
+
+fn set_pending_slashes(env: &Env, operator: &Address, count: u32) {
+    if count == 0 {
+        env.storage().persistent().remove(&DataKey::PendingSlashes(operator.clone()));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingSlashes(operator.clone()), &count);
+    }
+}
+
+fn pending_slash_proposal(env: &Env, slash_id: u64) -> SlashProposal {
+    let proposal: SlashProposal = env
+        .storage()
+        .persistent()
+        .get(&DataKey::SlashProposal(slash_id))
+        .expect("Slash proposal not found");
+    if proposal.executed {
+        panic!("Slash already executed");
+    }
+    if proposal.cancelled {
+        panic!("Slash cancelled");
+    }
+    proposal
+}
+
+/// Transfers `amount` of an operator's bond back to it, leaving `remaining` bonded.
+fn pay_out_bond(env: &Env, operator: &Address, amount: i128, remaining: i128) {
+    if remaining == 0 {
+        env.storage().persistent().remove(&DataKey::OperatorBond(operator.clone()));
+        env.storage().persistent().remove(&DataKey::UnbondingSince(operator.clone()));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&DataKey::OperatorBond(operator.clone()), &remaining);
+    }
+
+    let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+        .unwrap_or_else(|| panic!("Contract not initialized"));
+    soroban_sdk::token::Client::new(env, &config.fee_token).transfer(
+        &env.current_contract_address(),
+        operator,
+        &amount,
+    );
+
+    env.events().publish(
+        (symbol_short!("bridge"), symbol_short!("unbond")),
+        (operator.clone(), amount, remaining),
+    );
+}
+
+/// Executes a locked request once the destination chain's requirements on
+/// `evidence` are met.
+fn execute_request(env: Env, operator: Address, request_id: u64, evidence: Option<ExecutionEvidence>) {
+    operator.require_auth();
+    require_non_zero_address(&operator);
+    require_non_zero_u64(request_id, "request_id");
+    require_operator(&env, &operator);
+    require_not_paused(&env);
+    require_not_frozen(&env, request_id);
+
+    let mut request: MultisigBridgeRequest = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Request(request_id))
+        .expect("Request not found");
+
+    if request.status != BridgeOperationStatus::Locked {
+        panic!("Request not ready");
+    }
+
+    let tx_hash = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, &request_id.to_be_bytes()));
+    require_execution_evidence(&env, request.destination_chain, &tx_hash, &evidence);
+    require_checkpoint_inclusion(&env, request.destination_chain, &tx_hash, &evidence);
+
+    let mut tx_counter: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::TxCounter)
+        .unwrap_or(0);
+    tx_counter += 1;
+    env.storage().instance().set(&DataKey::TxCounter, &tx_counter);
+
+    let sender = request.sender.clone();
+
+    let transaction = BridgeTransaction {
+        transaction_id: tx_counter,
+        token_id: request.token_id,
+        source_chain: request.source_chain,
+        destination_chain: request.destination_chain,
+        sender: sender.clone(),
+        recipient: request.recipient.clone(),
+        transaction_hash: tx_hash.clone(),
+        timestamp: env.ledger().timestamp(),
+        gas_used: 0,
+        status: BridgeOperationStatus::InTransit,
+        metadata: request.metadata.clone(),
+    };
+
+    request.status = BridgeOperationStatus::Completed;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Request(request_id), &request);
+    env.storage()
+        .persistent()
+        .set(&DataKey::VerifiedTx(tx_hash.clone()), &true);
+
+    let mut history: Vec<BridgeTransaction> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::History(sender.clone()))
+        .unwrap_or(Vec::new(&env));
+
+    if history.len() >= MAX_HISTORY_ITEMS {
+        history.remove(0);
+    }
+    history.push_back(transaction);
+    env.storage()
+        .persistent()
+        .set(&DataKey::History(sender), &history);
+
+    // Split a held service fee between the executing operator and the
+    // fee recipient at the rebate agreed when the request was created.
+    // The fee record is removed so it is paid out once.
+    if let Some((fee, rebate_bps)) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, (i128, u32)>(&DataKey::RequestFee(request_id))
+    {
+        env.storage().persistent().remove(&DataKey::RequestFee(request_id));
+
+        let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+        let rebate = fee * rebate_bps as i128 / 10_000;
+
+        let client = soroban_sdk::token::Client::new(&env, &config.fee_token);
+        if rebate > 0 {
+            client.transfer(&env.current_contract_address(), &operator, &rebate);
+        }
+        if fee > rebate {
+            client.transfer(&env.current_contract_address(), &config.fee_recipient, &(fee - rebate));
+        }
+
+        env.events().publish(
+            (symbol_short!("bridge"), symbol_short!("rebate")),
+            (request_id, operator.clone(), rebate),
+        );
+    }
+
+    env.events().publish(
+        (symbol_short!("bridge"), symbol_short!("executed")),
+        (request_id, tx_hash),
+    );
+}
+
+/// Cuts `percent_bps` of an operator's bond and sends it to the fee
+/// recipient. Returns the amount slashed.
+fn slash_bond(env: &Env, operator: &Address, percent_bps: u32) -> i128 {
+    let bond = operator_bond(env, operator);
+    let amount = bond * percent_bps as i128 / 10_000;
+    if amount <= 0 {
+        return 0;
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::OperatorBond(operator.clone()), &(bond - amount));
+
+    let config: BridgeConfig = env.storage().instance().get(&DataKey::Config)
+        .unwrap_or_else(|| panic!("Contract not initialized"));
+    soroban_sdk::token::Client::new(env, &config.fee_token).transfer(
+        &env.current_contract_address(),
+        &config.fee_recipient,
+        &amount,
+    );
+    amount
+}
+
+/// Drops the per-operator settings of a removed operator and starts the
+/// unbonding delay on its bond.
+fn forget_operator(env: &Env, operator: &Address) {
+    env.storage().persistent().remove(&DataKey::OperatorLastActive(operator.clone()));
+    env.storage().persistent().remove(&DataKey::OperatorWeight(operator.clone()));
+    env.storage().persistent().remove(&DataKey::OperatorGroup(operator.clone()));
+    start_unbonding(env, operator);
+}
+
+/// Starts the unbonding delay for a removed operator's bond. The bond stays
+/// slashable until the operator withdraws it.
+fn start_unbonding(env: &Env, operator: &Address) {
+    if operator_bond(env, operator) > 0 {
+        env.storage()
+            .persistent()
+            .set(&DataKey::UnbondingSince(operator.clone()), &env.ledger().timestamp());
+    }
+}
+
+#[cfg(test)]
+mod tests;
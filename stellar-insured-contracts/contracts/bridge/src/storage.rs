@@ -17,6 +17,70 @@ pub enum DataKey {
     ReqCounter,
     TxCounter,
     Nonce(Address),
+    /// Timestamp of the operator's last signature, or of when it was added if it
+    /// has never signed. Used to detect inactive operators.
+    OperatorLastActive(Address),
+    /// Share of the service fee (in basis points) rebated to the executing operator.
+    ExecutorRebateBps,
+    /// Service fee held by the contract until the request is executed, with
+    /// the executor rebate (in basis points) in force when it was created.
+    RequestFee(u64),
+    /// Group (the entity running the key) an operator belongs to. Untagged
+    /// operators share group `0`.
+    OperatorGroup(Address),
+    /// Distinct operator groups that must sign before a request locks.
+    MinDistinctGroups,
+    /// Timestamp of the last heartbeat an operator recorded for a chain.
+    ChainHeartbeat(u32),
+    /// Oldest heartbeat, in seconds, a destination chain may have before new
+    /// requests to it are rejected. Zero disables the check.
+    MaxHeartbeatAge,
+    /// Per-chain override of `min_signatures_required`.
+    ChainMinSignatures(u32),
+    /// Voting weight of an operator's signature. Operators without an entry weigh 1.
+    OperatorWeight(Address),
+    /// Signature scheme used by attesters on a remote chain.
+    ChainSignatureScheme(u32),
+    /// Public key of the attester on a remote chain that must sign requests
+    /// to it before they execute.
+    ChainAttester(u32),
+    /// Freezes placed on a request, oldest first. The request is frozen while
+    /// the latest entry has not been released.
+    RequestFreezes(u64),
+    /// Most a single operator may contribute towards a request's signature
+    /// threshold, in basis points of that threshold. Zero disables the cap.
+    MaxSingleOperatorBps,
+    /// Operators that submitted a given state root for a remote block.
+    CheckpointVotes(u32, u64, BytesN<32>),
+    /// Distinct state roots voted for at a remote block while it is uncommitted.
+    CheckpointRoots(u32, u64),
+    /// State root committed for a remote block once operators reached quorum.
+    Checkpoint(u32, u64),
+    /// Highest remote block with a committed checkpoint. Requests to a chain
+    /// with one need an inclusion proof to execute.
+    LatestCheckpoint(u32),
+    /// Active pause on new requests towards a destination chain.
+    ChainPause(u32),
+    /// Fee-token stake an operator has bonded against misbehaviour.
+    OperatorBond(Address),
+    /// Proposed cut of an operator's bond, by slash id.
+    SlashProposal(u64),
+    /// Last slash id handed out.
+    SlashCounter,
+    /// Seconds a slash proposal must wait before it can be executed.
+    SlashReviewPeriod,
+    /// Slash proposals against an operator that are neither executed nor
+    /// cancelled. The bond cannot be withdrawn while any are pending.
+    PendingSlashes(Address),
+    /// When an operator was removed. Its bond can be withdrawn once a slash
+    /// review period has passed since then.
+    UnbondingSince(Address),
+    /// Share of its bond, in basis points, slashed from an operator removed
+    /// for inactivity. Absent means inactive operators are not slashed.
+    InactivitySlashBps,
+    /// Governance contract allowed to propose, execute and cancel slashes
+    /// alongside the admin.
+    SlashGovernance,
 }
 
 /// Maximum bridge history entries retained per account (prevents unbounded growth).
@@ -0,0 +1,945 @@
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, vec, Bytes, BytesN, Env};
+
+fn setup(env: &Env) -> (PropertyBridgeClient<'_>, Address) {
+    let (client, admin, _fee_token) = setup_with_fee(env, 0);
+    (client, admin)
+}
+
+fn setup_with_fee(env: &Env, service_fee: i128) -> (PropertyBridgeClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, PropertyBridge);
+    let client = PropertyBridgeClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let fee_token = env.register_stellar_asset_contract(Address::generate(env));
+    client.init(
+        &admin,
+        &vec![env, 2u32, 3u32],
+        &1,
+        &3,
+        &3_600,
+        &1_000_000,
+        &service_fee,
+        &fee_token,
+        &Address::generate(env),
+    );
+    (client, admin, fee_token)
+}
+
+fn metadata(env: &Env) -> PropertyMetadata {
+    PropertyMetadata {
+        location: String::from_str(env, "Lagos"),
+        size: 120,
+        legal_description: String::from_str(env, "Plot 7"),
+        valuation: 500_000,
+        documents_url: String::from_str(env, "ipfs://docs"),
+    }
+}
+
+fn open_request(env: &Env, client: &PropertyBridgeClient<'_>, sender: &Address, nonce: u64) -> u64 {
+    client.initiate_bridge_multisig(
+        sender,
+        &1,
+        &2,
+        &Address::generate(env),
+        &2,
+        &None,
+        &metadata(env),
+        &nonce,
+    )
+}
+
+#[test]
+fn test_penalize_inactive_operators_removes_only_silent_ones() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let active = Address::generate(&env);
+    let silent = Address::generate(&env);
+    client.add_operator(&admin, &active);
+    client.add_operator(&admin, &silent);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let sender = Address::generate(&env);
+    let request_id = open_request(&env, &client, &sender, 1);
+    client.sign_bridge_request(&active, &request_id, &true);
+    client.sign_bridge_request(&admin, &request_id, &true);
+
+    let inactive = client.get_inactive_operators(&5_000);
+    assert_eq!(inactive, vec![&env, silent.clone()]);
+
+    let removed = client.penalize_inactive_operators(&admin, &5_000);
+    assert_eq!(removed, vec![&env, silent.clone()]);
+    assert!(client.is_operator(&active));
+    assert!(client.is_operator(&admin));
+    assert!(!client.is_operator(&silent));
+    assert_eq!(client.get_operator_last_active(&silent), None);
+    assert!(client.get_operator_last_active(&active).is_some());
+}
+
+#[test]
+fn test_penalize_inactive_operators_keeps_minimum_set() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let silent = Address::generate(&env);
+    client.add_operator(&admin, &silent);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let removed = client.penalize_inactive_operators(&admin, &5_000);
+
+    // min_signatures_required is 1, so one operator must survive.
+    assert_eq!(removed.len(), 1);
+    assert_eq!(client.get_inactive_operators(&5_000).len(), 1);
+}
+
+#[test]
+fn test_executor_rebate_split_on_execute() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 1_000);
+    let balances = token::Client::new(&env, &fee_token);
+    let fee_recipient = client.get_config().fee_recipient;
+    client.set_executor_rebate(&admin, &2_500);
+
+    let sender = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &fee_token).mint(&sender, &1_000);
+    let request_id = open_request(&env, &client, &sender, 1);
+    assert_eq!(balances.balance(&client.address), 1_000);
+    assert_eq!(client.get_request_fee(&request_id), Some((1_000, 2_500)));
+
+    // Changing the rebate does not affect requests already holding a fee
+    client.set_executor_rebate(&admin, &10_000);
+    let executor = Address::generate(&env);
+    client.add_operator(&admin, &executor);
+    client.sign_bridge_request(&admin, &request_id, &true);
+    client.sign_bridge_request(&executor, &request_id, &true);
+    client.execute_bridge(&executor, &request_id);
+
+    assert_eq!(balances.balance(&executor), 250);
+    assert_eq!(balances.balance(&fee_recipient), 750);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(client.get_request_fee(&request_id), None);
+}
+
+#[test]
+fn test_single_operator_group_cannot_lock_request() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let key_a = Address::generate(&env);
+    let key_b = Address::generate(&env);
+    let other = Address::generate(&env);
+    for op in [&key_a, &key_b, &other] {
+        client.add_operator(&admin, op);
+    }
+    client.set_operator_group(&admin, &key_a, &1);
+    client.set_operator_group(&admin, &key_b, &1);
+    client.set_operator_group(&admin, &other, &2);
+    client.set_min_distinct_groups(&admin, &2);
+
+    let sender = Address::generate(&env);
+    let request_id = open_request(&env, &client, &sender, 1);
+    client.sign_bridge_request(&key_a, &request_id, &true);
+    client.sign_bridge_request(&key_b, &request_id, &true);
+    assert_eq!(
+        client.get_request(&request_id).unwrap().status,
+        BridgeOperationStatus::Pending
+    );
+
+    client.sign_bridge_request(&other, &request_id, &true);
+    assert_eq!(
+        client.get_request(&request_id).unwrap().status,
+        BridgeOperationStatus::Locked
+    );
+}
+
+#[test]
+#[should_panic(expected = "Chain heartbeat stale")]
+fn test_stale_chain_heartbeat_blocks_requests() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_max_heartbeat_age(&admin, &600);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.record_chain_heartbeat(&admin, &2);
+    env.ledger().with_mut(|li| li.timestamp = 1_601);
+
+    let sender = Address::generate(&env);
+    open_request(&env, &client, &sender, 1);
+}
+
+#[test]
+fn test_fresh_chain_heartbeat_reenables_requests() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_max_heartbeat_age(&admin, &600);
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    client.record_chain_heartbeat(&admin, &2);
+    assert_eq!(client.get_chain_last_heartbeat(&2), Some(5_000));
+
+    let sender = Address::generate(&env);
+    let request_id = open_request(&env, &client, &sender, 1);
+    assert!(client.get_request(&request_id).is_some());
+}
+
+#[test]
+fn test_sign_batch_skips_signed_and_expired_requests() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let sender = Address::generate(&env);
+    let fresh = open_request(&env, &client, &sender, 1);
+    let signed = open_request(&env, &client, &sender, 2);
+    let expiring = client.initiate_bridge_multisig(
+        &sender,
+        &1,
+        &2,
+        &Address::generate(&env),
+        &2,
+        &Some(100),
+        &metadata(&env),
+        &3,
+    );
+    client.sign_bridge_request(&admin, &signed, &true);
+    env.ledger().with_mut(|li| li.timestamp = 101);
+
+    let results = client.sign_bridge_requests_batch(&admin, &vec![&env, fresh, signed, expiring, 99]);
+    assert_eq!(results, vec![&env, 1u32, u32::MAX, u32::MAX, u32::MAX]);
+    assert_eq!(client.get_request(&fresh).unwrap().signatures.len(), 1);
+    assert_eq!(client.get_request(&expiring).unwrap().signatures.len(), 0);
+}
+
+#[test]
+fn test_sign_batch_skips_requests_no_longer_pending() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let operator = Address::generate(&env);
+    client.add_operator(&admin, &operator);
+    let locked = open_request_with_signatures(&env, &client, 2, 1);
+    let failed = open_request_with_signatures(&env, &client, 2, 1);
+    client.sign_bridge_request(&admin, &locked, &true);
+    client.sign_bridge_request(&admin, &failed, &false);
+
+    let results = client.sign_bridge_requests_batch(&operator, &vec![&env, locked, failed]);
+    assert_eq!(results, vec![&env, u32::MAX, u32::MAX]);
+    assert_eq!(client.get_request(&locked).unwrap().signatures.len(), 1);
+    assert_eq!(client.get_request(&failed).unwrap().status, BridgeOperationStatus::Failed);
+}
+
+fn open_request_with_signatures(
+    env: &Env,
+    client: &PropertyBridgeClient<'_>,
+    destination_chain: u32,
+    required_signatures: u32,
+) -> u64 {
+    client.initiate_bridge_multisig(
+        &Address::generate(env),
+        &1,
+        &destination_chain,
+        &Address::generate(env),
+        &required_signatures,
+        &None,
+        &metadata(env),
+        &1,
+    )
+}
+
+#[test]
+fn test_chain_min_signatures_override() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_chain_min_signatures(&admin, &2, &3);
+    assert_eq!(client.get_chain_min_signatures(&2), 3);
+    assert_eq!(client.get_chain_min_signatures(&3), 1);
+
+    let request_id = open_request_with_signatures(&env, &client, 2, 3);
+    assert_eq!(client.get_request(&request_id).unwrap().required_signatures, 3);
+    // The other chain keeps the global minimum
+    open_request_with_signatures(&env, &client, 3, 1);
+}
+
+#[test]
+#[should_panic(expected = "Invalid signature requirement")]
+fn test_chain_min_signatures_rejects_lower_requirement() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_chain_min_signatures(&admin, &2, &3);
+    open_request_with_signatures(&env, &client, 2, 2);
+}
+
+#[test]
+fn test_weighted_operators_reach_threshold() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let heavy_a = Address::generate(&env);
+    let heavy_b = Address::generate(&env);
+    client.add_operator(&admin, &heavy_a);
+    client.add_operator(&admin, &heavy_b);
+    client.set_operator_weight(&admin, &heavy_a, &2);
+    client.set_operator_weight(&admin, &heavy_b, &2);
+
+    let request_id = open_request_with_signatures(&env, &client, 2, 3);
+    client.sign_bridge_request(&heavy_a, &request_id, &true);
+    assert_eq!(client.get_signature_weight(&request_id), 2);
+    assert_eq!(
+        client.get_request(&request_id).unwrap().status,
+        BridgeOperationStatus::Pending
+    );
+
+    client.sign_bridge_request(&heavy_b, &request_id, &true);
+    assert_eq!(client.get_signature_weight(&request_id), 4);
+    assert_eq!(
+        client.get_request(&request_id).unwrap().status,
+        BridgeOperationStatus::Locked
+    );
+}
+
+#[test]
+fn test_expire_stale_requests_sweeps_once() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let sender = Address::generate(&env);
+    let open = open_request(&env, &client, &sender, 1);
+    let stale = client.initiate_bridge_multisig(
+        &sender,
+        &1,
+        &2,
+        &Address::generate(&env),
+        &2,
+        &Some(100),
+        &metadata(&env),
+        &2,
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 101);
+    assert_eq!(client.expire_stale_requests(&vec![&env, open, stale, 99]), 1);
+    assert_eq!(
+        client.get_request(&stale).unwrap().status,
+        BridgeOperationStatus::Expired
+    );
+    assert_eq!(
+        client.get_request(&open).unwrap().status,
+        BridgeOperationStatus::Pending
+    );
+
+    // Already expired requests are skipped
+    assert_eq!(client.expire_stale_requests(&vec![&env, stale]), 0);
+}
+
+#[test]
+fn test_get_operators_paginated() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    client.add_operator(&admin, &second);
+    client.add_operator(&admin, &third);
+
+    let (page, total) = client.get_operators_paginated(&0, &2);
+    assert_eq!(page, vec![&env, admin.clone(), second.clone()]);
+    assert_eq!(total, 3);
+
+    let (page, total) = client.get_operators_paginated(&2, &10);
+    assert_eq!(page, vec![&env, third.clone()]);
+    assert_eq!(total, 3);
+
+    let (page, total) = client.get_operators_paginated(&5, &10);
+    assert_eq!(page.len(), 0);
+    assert_eq!(total, 3);
+}
+
+fn ed25519_signer() -> ed25519_dalek::SigningKey {
+    ed25519_dalek::SigningKey::from_bytes(&[7u8; 32])
+}
+
+fn secp256k1_signer() -> k256::ecdsa::SigningKey {
+    k256::ecdsa::SigningKey::from_slice(&[9u8; 32]).unwrap()
+}
+
+const ATTESTED_MESSAGE: &[u8] = b"lock:42";
+
+fn ed25519_attestation(env: &Env) -> (Bytes, BytesN<64>) {
+    use ed25519_dalek::Signer;
+    let signer = ed25519_signer();
+    let signature = signer.sign(ATTESTED_MESSAGE);
+    (
+        Bytes::from_slice(env, signer.verifying_key().as_bytes()),
+        BytesN::from_array(env, &signature.to_bytes()),
+    )
+}
+
+fn secp256k1_attestation(env: &Env) -> (Bytes, BytesN<64>, u32) {
+    let signer = secp256k1_signer();
+    let message = Bytes::from_slice(env, ATTESTED_MESSAGE);
+    let digest = env.crypto().keccak256(&message).to_array();
+    let (signature, recovery_id) = signer.sign_prehash_recoverable(&digest).unwrap();
+    let public_key = signer.verifying_key().to_encoded_point(false);
+    (
+        Bytes::from_slice(env, public_key.as_bytes()),
+        BytesN::from_array(env, &signature.to_bytes().into()),
+        recovery_id.to_byte() as u32,
+    )
+}
+
+#[test]
+fn test_ed25519_chain_attestation_verifies() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_chain_signature_scheme(&admin, &2, &SignatureScheme::Ed25519);
+    assert_eq!(client.get_chain_signature_scheme(&2), Some(SignatureScheme::Ed25519));
+
+    let message = Bytes::from_slice(&env, ATTESTED_MESSAGE);
+    let (public_key, signature) = ed25519_attestation(&env);
+    client.verify_chain_attestation(&2, &public_key, &message, &signature, &0);
+}
+
+#[test]
+fn test_secp256k1_chain_attestation_verifies() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_chain_signature_scheme(&admin, &3, &SignatureScheme::Secp256k1);
+
+    let message = Bytes::from_slice(&env, ATTESTED_MESSAGE);
+    let (public_key, signature, recovery_id) = secp256k1_attestation(&env);
+    client.verify_chain_attestation(&3, &public_key, &message, &signature, &recovery_id);
+}
+
+#[test]
+#[should_panic(expected = "Signature scheme mismatch")]
+fn test_ed25519_attestation_rejected_on_secp256k1_chain() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_chain_signature_scheme(&admin, &3, &SignatureScheme::Secp256k1);
+
+    let message = Bytes::from_slice(&env, ATTESTED_MESSAGE);
+    let (public_key, signature) = ed25519_attestation(&env);
+    client.verify_chain_attestation(&3, &public_key, &message, &signature, &0);
+}
+
+#[test]
+#[should_panic(expected = "Signature scheme mismatch")]
+fn test_secp256k1_attestation_rejected_on_ed25519_chain() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_chain_signature_scheme(&admin, &2, &SignatureScheme::Ed25519);
+
+    let message = Bytes::from_slice(&env, ATTESTED_MESSAGE);
+    let (public_key, signature, recovery_id) = secp256k1_attestation(&env);
+    client.verify_chain_attestation(&2, &public_key, &message, &signature, &recovery_id);
+}
+
+#[test]
+#[should_panic(expected = "Unsupported signature scheme")]
+fn test_attestation_rejected_without_scheme() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    let message = Bytes::from_slice(&env, ATTESTED_MESSAGE);
+    let (public_key, signature) = ed25519_attestation(&env);
+    client.verify_chain_attestation(&2, &public_key, &message, &signature, &0);
+}
+
+/// A request to chain 2 signed by enough operators to execute, and one of them.
+fn locked_request(env: &Env, client: &PropertyBridgeClient<'_>, admin: &Address) -> (u64, Address) {
+    let operator = Address::generate(env);
+    client.add_operator(admin, &operator);
+    let request_id = open_request(env, client, &Address::generate(env), 1);
+    client.sign_bridge_request(admin, &request_id, &true);
+    client.sign_bridge_request(&operator, &request_id, &true);
+    (request_id, operator)
+}
+
+fn attest_request(env: &Env, request_id: u64) -> ExecutionEvidence {
+    use ed25519_dalek::Signer;
+    let tx_hash = env
+        .crypto()
+        .sha256(&Bytes::from_slice(env, &request_id.to_be_bytes()))
+        .to_array();
+    ExecutionEvidence {
+        signature: BytesN::from_array(env, &ed25519_signer().sign(&tx_hash).to_bytes()),
+        recovery_id: 0,
+        block_height: 0,
+        proof: Vec::new(env),
+    }
+}
+
+fn attested_chain(env: &Env, client: &PropertyBridgeClient<'_>, admin: &Address) {
+    client.set_chain_signature_scheme(admin, &2, &SignatureScheme::Ed25519);
+    let (public_key, _signature) = ed25519_attestation(env);
+    client.set_chain_attester(admin, &2, &public_key);
+}
+
+#[test]
+fn test_attested_chain_executes_with_attester_signature() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    attested_chain(&env, &client, &admin);
+    let (request_id, operator) = locked_request(&env, &client, &admin);
+
+    client.execute_bridge_with_evidence(&operator, &request_id, &attest_request(&env, request_id));
+    assert_eq!(client.get_request(&request_id).unwrap().status, BridgeOperationStatus::Completed);
+}
+
+#[test]
+#[should_panic(expected = "Attestation required")]
+fn test_attested_chain_rejects_execution_without_attestation() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    attested_chain(&env, &client, &admin);
+    let (request_id, operator) = locked_request(&env, &client, &admin);
+
+    client.execute_bridge(&operator, &request_id);
+}
+
+#[test]
+fn test_attested_chain_rejects_signature_over_other_request() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    attested_chain(&env, &client, &admin);
+    let (request_id, operator) = locked_request(&env, &client, &admin);
+
+    let forged = attest_request(&env, request_id + 1);
+    assert!(client.try_execute_bridge_with_evidence(&operator, &request_id, &forged).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Signature scheme mismatch")]
+fn test_chain_attester_key_must_fit_scheme() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_chain_signature_scheme(&admin, &3, &SignatureScheme::Secp256k1);
+    let (public_key, _signature) = ed25519_attestation(&env);
+    client.set_chain_attester(&admin, &3, &public_key);
+}
+
+#[test]
+#[should_panic(expected = "Request frozen")]
+fn test_frozen_request_cannot_be_signed() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let sender = Address::generate(&env);
+    let request_id = open_request(&env, &client, &sender, 1);
+
+    client.freeze_request(&admin, &request_id, &String::from_str(&env, "suspicious sender"));
+    assert!(client.is_request_frozen(&request_id));
+    client.sign_bridge_request(&admin, &request_id, &true);
+}
+
+#[test]
+#[should_panic(expected = "Request frozen")]
+fn test_frozen_request_cannot_be_executed() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let sender = Address::generate(&env);
+    let request_id = open_request(&env, &client, &sender, 1);
+    let operator = Address::generate(&env);
+    client.add_operator(&admin, &operator);
+    client.sign_bridge_request(&admin, &request_id, &true);
+    client.sign_bridge_request(&operator, &request_id, &true);
+
+    client.freeze_request(&admin, &request_id, &String::from_str(&env, "suspicious sender"));
+    client.execute_bridge(&admin, &request_id);
+}
+
+#[test]
+fn test_unfrozen_request_resumes_and_others_unaffected() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let sender = Address::generate(&env);
+    let frozen = open_request(&env, &client, &sender, 1);
+    let other = open_request(&env, &client, &sender, 2);
+
+    client.freeze_request(&admin, &frozen, &String::from_str(&env, "suspicious sender"));
+    client.sign_bridge_request(&admin, &other, &true);
+    assert!(!client.is_request_frozen(&other));
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    client.unfreeze_request(&admin, &frozen);
+    client.sign_bridge_request(&admin, &frozen, &true);
+    assert_eq!(client.get_request(&frozen).unwrap().signatures.len(), 1);
+
+    let freezes = client.get_request_freezes(&frozen);
+    assert_eq!(freezes.len(), 1);
+    assert_eq!(freezes.get(0).unwrap().released_at, Some(500));
+}
+
+#[test]
+fn test_frozen_request_does_not_expire() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let stale = client.initiate_bridge_multisig(
+        &Address::generate(&env),
+        &1,
+        &2,
+        &Address::generate(&env),
+        &2,
+        &Some(100),
+        &metadata(&env),
+        &1,
+    );
+    client.freeze_request(&admin, &stale, &String::from_str(&env, "under review"));
+
+    env.ledger().with_mut(|li| li.timestamp = 101);
+    assert_eq!(client.expire_stale_requests(&vec![&env, stale]), 0);
+    assert_eq!(
+        client.get_request(&stale).unwrap().status,
+        BridgeOperationStatus::Pending
+    );
+}
+
+#[test]
+fn test_dominant_operator_weight_is_capped() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let dominant = Address::generate(&env);
+    let minor = Address::generate(&env);
+    client.add_operator(&admin, &dominant);
+    client.add_operator(&admin, &minor);
+    client.set_operator_weight(&admin, &dominant, &10);
+    client.set_max_single_operator_bps(&admin, &6_000);
+
+    // A threshold of 3 caps any one operator at 1 (60% of 3, rounded down)
+    let request_id = open_request_with_signatures(&env, &client, 2, 3);
+    client.sign_bridge_request(&dominant, &request_id, &true);
+    assert_eq!(client.get_signature_weight(&request_id), 1);
+
+    client.sign_bridge_request(&minor, &request_id, &true);
+    assert_eq!(
+        client.get_request(&request_id).unwrap().status,
+        BridgeOperationStatus::Pending
+    );
+
+    client.sign_bridge_request(&admin, &request_id, &true);
+    assert_eq!(client.get_signature_weight(&request_id), 3);
+    assert_eq!(
+        client.get_request(&request_id).unwrap().status,
+        BridgeOperationStatus::Locked
+    );
+}
+
+fn sorted_pair_hash(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut pair = Bytes::from_array(env, &left.to_array());
+    pair.append(&Bytes::from_array(env, &right.to_array()));
+    BytesN::from_array(env, &env.crypto().sha256(&pair).to_array())
+}
+
+#[test]
+fn test_checkpoint_commits_at_quorum_and_verifies_proofs() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let second = Address::generate(&env);
+    client.add_operator(&admin, &second);
+    client.set_chain_min_signatures(&admin, &2, &2);
+
+    let leaf = BytesN::from_array(&env, &[1u8; 32]);
+    let sibling = BytesN::from_array(&env, &[2u8; 32]);
+    let root = sorted_pair_hash(&env, &leaf, &sibling);
+
+    assert!(!client.submit_checkpoint(&admin, &2, &100, &root));
+    assert_eq!(client.get_checkpoint(&2, &100), None);
+
+    assert!(client.submit_checkpoint(&second, &2, &100, &root));
+    assert_eq!(client.get_checkpoint(&2, &100), Some(root.clone()));
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&DataKey::CheckpointVotes(2, 100, root)));
+        assert!(!env.storage().persistent().has(&DataKey::CheckpointRoots(2, 100)));
+    });
+
+    assert!(client.verify_checkpoint_proof(&2, &100, &leaf, &vec![&env, sibling.clone()]));
+    let forged = BytesN::from_array(&env, &[3u8; 32]);
+    assert!(!client.verify_checkpoint_proof(&2, &100, &forged, &vec![&env, sibling]));
+}
+
+/// Commits a checkpoint of chain 2 at block 100 that includes `request_id`,
+/// and returns evidence proving it.
+fn checkpoint_request(env: &Env, client: &PropertyBridgeClient<'_>, admin: &Address, request_id: u64) -> ExecutionEvidence {
+    let leaf = BytesN::from_array(
+        env,
+        &env.crypto().sha256(&Bytes::from_slice(env, &request_id.to_be_bytes())).to_array(),
+    );
+    let sibling = BytesN::from_array(env, &[2u8; 32]);
+    client.submit_checkpoint(admin, &2, &100, &sorted_pair_hash(env, &leaf, &sibling));
+    ExecutionEvidence {
+        signature: BytesN::from_array(env, &[0u8; 64]),
+        recovery_id: 0,
+        block_height: 100,
+        proof: vec![env, sibling],
+    }
+}
+
+#[test]
+fn test_checkpointed_chain_executes_with_inclusion_proof() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let (request_id, operator) = locked_request(&env, &client, &admin);
+    let evidence = checkpoint_request(&env, &client, &admin, request_id);
+    assert_eq!(client.get_latest_checkpoint(&2), Some(100));
+
+    client.execute_bridge_with_evidence(&operator, &request_id, &evidence);
+    assert_eq!(client.get_request(&request_id).unwrap().status, BridgeOperationStatus::Completed);
+}
+
+#[test]
+#[should_panic(expected = "Checkpoint proof required")]
+fn test_checkpointed_chain_rejects_execution_without_proof() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let (request_id, operator) = locked_request(&env, &client, &admin);
+    checkpoint_request(&env, &client, &admin, request_id);
+
+    client.execute_bridge(&operator, &request_id);
+}
+
+#[test]
+#[should_panic(expected = "Invalid checkpoint proof")]
+fn test_checkpointed_chain_rejects_proof_for_other_request() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let (request_id, operator) = locked_request(&env, &client, &admin);
+    let evidence = checkpoint_request(&env, &client, &admin, request_id + 1);
+
+    client.execute_bridge_with_evidence(&operator, &request_id, &evidence);
+}
+
+#[test]
+#[should_panic(expected = "Already signed")]
+fn test_checkpoint_one_vote_per_operator_per_block() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_chain_min_signatures(&admin, &2, &2);
+
+    client.submit_checkpoint(&admin, &2, &100, &BytesN::from_array(&env, &[1u8; 32]));
+    client.submit_checkpoint(&admin, &2, &100, &BytesN::from_array(&env, &[2u8; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Checkpoint not committed")]
+fn test_checkpoint_below_quorum_cannot_verify() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_chain_min_signatures(&admin, &2, &2);
+
+    let leaf = BytesN::from_array(&env, &[1u8; 32]);
+    let sibling = BytesN::from_array(&env, &[2u8; 32]);
+    let root = sorted_pair_hash(&env, &leaf, &sibling);
+    client.submit_checkpoint(&admin, &2, &100, &root);
+
+    client.verify_checkpoint_proof(&2, &100, &leaf, &vec![&env, sibling]);
+}
+
+#[test]
+fn test_chain_pause_blocks_new_requests_until_lifted() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let sender = Address::generate(&env);
+    env.ledger().with_mut(|li| li.timestamp = 700);
+
+    client.pause_chain(&admin, &2, &symbol_short!("incident"));
+    let pause = client.get_chain_pause(&2).unwrap();
+    assert_eq!(pause.reason, symbol_short!("incident"));
+    assert_eq!(pause.paused_at, 700);
+
+    let blocked = client.try_initiate_bridge_multisig(
+        &sender,
+        &1,
+        &2,
+        &Address::generate(&env),
+        &2,
+        &None,
+        &metadata(&env),
+        &1,
+    );
+    assert!(blocked.is_err());
+
+    client.unpause_chain(&admin, &2);
+    assert_eq!(client.get_chain_pause(&2), None);
+    open_request(&env, &client, &sender, 1);
+}
+
+fn bonded_operator(env: &Env, client: &PropertyBridgeClient<'_>, admin: &Address, fee_token: &Address) -> Address {
+    let operator = Address::generate(env);
+    client.add_operator(admin, &operator);
+    token::StellarAssetClient::new(env, fee_token).mint(&operator, &10_000);
+    client.bond_operator(&operator, &10_000);
+    operator
+}
+
+#[test]
+fn test_slash_takes_share_of_bond_after_review() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 0);
+    let operator = bonded_operator(&env, &client, &admin, &fee_token);
+    client.set_slash_review_period(&admin, &3_600);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let evidence = BytesN::from_array(&env, &[9u8; 32]);
+    let slash_id = client.propose_slash(&admin, &operator, &1_000, &evidence);
+    assert!(client.try_execute_slash(&admin, &slash_id).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp = 4_600);
+    assert_eq!(client.execute_slash(&admin, &slash_id), 1_000);
+    assert_eq!(client.get_operator_bond(&operator), 9_000);
+    assert!(client.get_slash_proposal(&slash_id).unwrap().executed);
+    let fee_recipient = client.get_config().fee_recipient;
+    assert_eq!(token::Client::new(&env, &fee_token).balance(&fee_recipient), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Slash percent too high")]
+fn test_slash_above_maximum_rejected() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 0);
+    let operator = bonded_operator(&env, &client, &admin, &fee_token);
+    client.propose_slash(&admin, &operator, &1_001, &BytesN::from_array(&env, &[9u8; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Slash already executed")]
+fn test_slash_executes_only_once() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 0);
+    let operator = bonded_operator(&env, &client, &admin, &fee_token);
+    let slash_id = client.propose_slash(&admin, &operator, &500, &BytesN::from_array(&env, &[9u8; 32]));
+    client.execute_slash(&admin, &slash_id);
+    client.execute_slash(&admin, &slash_id);
+}
+
+#[test]
+fn test_slash_governance_can_propose_and_execute() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 0);
+    let operator = bonded_operator(&env, &client, &admin, &fee_token);
+    let governance = Address::generate(&env);
+    client.set_slash_governance(&admin, &governance);
+
+    let slash_id = client.propose_slash(&governance, &operator, &500, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_eq!(client.get_slash_proposal(&slash_id).unwrap().proposed_by, governance);
+    assert_eq!(client.execute_slash(&governance, &slash_id), 500);
+    assert_eq!(client.get_operator_bond(&operator), 9_500);
+}
+
+#[test]
+fn test_cancelled_slash_frees_bond_for_withdrawal() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 0);
+    let operator = bonded_operator(&env, &client, &admin, &fee_token);
+
+    client.remove_operator(&admin, &operator);
+
+    let slash_id = client.propose_slash(&admin, &operator, &500, &BytesN::from_array(&env, &[9u8; 32]));
+    client.cancel_slash(&admin, &slash_id);
+    assert!(client.get_slash_proposal(&slash_id).unwrap().cancelled);
+
+    client.withdraw_bond(&operator, &4_000);
+    assert_eq!(client.get_operator_bond(&operator), 6_000);
+    assert_eq!(token::Client::new(&env, &fee_token).balance(&operator), 4_000);
+}
+
+#[test]
+#[should_panic(expected = "Slash pending")]
+fn test_withdraw_bond_blocked_while_slash_pending() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 0);
+    let operator = bonded_operator(&env, &client, &admin, &fee_token);
+    client.remove_operator(&admin, &operator);
+    client.propose_slash(&admin, &operator, &500, &BytesN::from_array(&env, &[9u8; 32]));
+    client.withdraw_bond(&operator, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Slash cancelled")]
+fn test_cancelled_slash_cannot_execute() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 0);
+    let operator = bonded_operator(&env, &client, &admin, &fee_token);
+    let slash_id = client.propose_slash(&admin, &operator, &500, &BytesN::from_array(&env, &[9u8; 32]));
+    client.cancel_slash(&admin, &slash_id);
+    client.execute_slash(&admin, &slash_id);
+}
+
+#[test]
+fn test_removed_operator_withdraws_bond_after_unbonding() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 0);
+    let operator = bonded_operator(&env, &client, &admin, &fee_token);
+    client.set_slash_review_period(&admin, &3_600);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.remove_operator(&admin, &operator);
+    assert_eq!(client.get_unbonding_since(&operator), Some(1_000));
+    assert_eq!(client.get_operator_bond(&operator), 10_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 4_599);
+    assert!(client.try_withdraw_bond(&operator, &10_000).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp = 4_600);
+    client.withdraw_bond(&operator, &10_000);
+    assert_eq!(client.get_operator_bond(&operator), 0);
+    assert_eq!(client.get_unbonding_since(&operator), None);
+    assert_eq!(token::Client::new(&env, &fee_token).balance(&operator), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Operator must be removed before unbonding")]
+fn test_active_operator_cannot_withdraw_bond() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 0);
+    let operator = bonded_operator(&env, &client, &admin, &fee_token);
+    client.withdraw_bond(&operator, &1_000);
+}
+
+#[test]
+fn test_slash_proposed_after_removal_reaches_bond() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 0);
+    let operator = bonded_operator(&env, &client, &admin, &fee_token);
+    client.set_slash_review_period(&admin, &3_600);
+    client.remove_operator(&admin, &operator);
+
+    let slash_id = client.propose_slash(&admin, &operator, &1_000, &BytesN::from_array(&env, &[9u8; 32]));
+    env.ledger().with_mut(|li| li.timestamp = 3_600);
+    assert!(client.try_withdraw_bond(&operator, &10_000).is_err());
+
+    assert_eq!(client.execute_slash(&admin, &slash_id), 1_000);
+    client.withdraw_bond(&operator, &9_000);
+    assert_eq!(token::Client::new(&env, &fee_token).balance(&operator), 9_000);
+}
+
+#[test]
+fn test_penalized_operator_slashed_and_bond_locked() {
+    let env = Env::default();
+    let (client, admin, fee_token) = setup_with_fee(&env, 0);
+    let operator = bonded_operator(&env, &client, &admin, &fee_token);
+    client.set_operator_weight(&admin, &operator, &3);
+    client.set_operator_group(&admin, &operator, &7);
+    client.set_inactivity_slash_bps(&admin, &500);
+    client.set_slash_review_period(&admin, &3_600);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.add_operator(&admin, &Address::generate(&env));
+    let removed = client.penalize_inactive_operators(&admin, &5_000);
+    assert!(removed.contains(operator.clone()));
+
+    let balances = token::Client::new(&env, &fee_token);
+    assert_eq!(client.get_operator_bond(&operator), 9_500);
+    assert_eq!(balances.balance(&client.get_config().fee_recipient), 500);
+    assert_eq!(balances.balance(&operator), 0);
+    assert_eq!(client.get_unbonding_since(&operator), Some(10_000));
+    assert_eq!(client.get_operator_weight(&operator), 1);
+    assert_eq!(client.get_operator_group(&operator), 0);
+}
+
+#[test]
+fn test_removed_operator_weight_and_group_cleared() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let operator = Address::generate(&env);
+    client.add_operator(&admin, &operator);
+    client.set_operator_weight(&admin, &operator, &3);
+    client.set_operator_group(&admin, &operator, &7);
+
+    client.remove_operator(&admin, &operator);
+    client.add_operator(&admin, &operator);
+    assert_eq!(client.get_operator_weight(&operator), 1);
+    assert_eq!(client.get_operator_group(&operator), 0);
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[contracttype]
@@ -11,6 +11,14 @@ pub enum BridgeOperationStatus {
     InTransit,
 }
 
+/// Signature scheme a remote chain's attesters sign with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum SignatureScheme {
+    Ed25519,
+    Secp256k1,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[contracttype]
 pub struct PropertyMetadata {
@@ -74,7 +82,8 @@ pub struct BridgeTransaction {
 pub struct ChainBridgeInfo {
     pub chain_id: u32,
     pub chain_name: String,
-    pub bridge_contract_address: Option<String>,
+    /// Bridge contract on the remote chain; empty until configured.
+    pub bridge_contract_address: String,
     pub is_active: bool,
     pub gas_multiplier: u32,
     pub confirmation_blocks: u32,
@@ -89,3 +98,52 @@ pub enum RecoveryAction {
     RetryBridge,
     CancelBridge,
 }
+
+/// Incident-response freeze on a single bridge request. `released_at` is set
+/// when the admin lifts it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct RequestFreeze {
+    pub frozen_by: Address,
+    pub reason: String,
+    pub frozen_at: u64,
+    pub released_at: Option<u64>,
+}
+
+/// Evidence from the destination chain supplied when executing a request
+/// to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ExecutionEvidence {
+    /// Attester signature over the request's transaction hash.
+    pub signature: BytesN<64>,
+    /// Secp256k1 recovery id; ignored for Ed25519.
+    pub recovery_id: u32,
+    /// Remote block whose committed checkpoint includes the transaction hash.
+    pub block_height: u64,
+    /// Merkle proof of the transaction hash against that checkpoint.
+    pub proof: Vec<BytesN<32>>,
+}
+
+/// Pause on bridging towards a single destination chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ChainPause {
+    pub paused_by: Address,
+    pub reason: Symbol,
+    pub paused_at: u64,
+}
+
+/// Proposed cut of an operator's bond. It can be executed once the review
+/// period has passed, and only once, unless it is cancelled first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct SlashProposal {
+    pub operator: Address,
+    pub percent_bps: u32,
+    pub evidence_hash: BytesN<32>,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    pub executed: bool,
+    pub cancelled: bool,
+}
@@ -1,7 +1,7 @@
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec};
 
 use crate::storage::DataKey;
-use crate::types::BridgeConfig;
+use crate::types::{BridgeConfig, ExecutionEvidence, RequestFreeze, SignatureScheme};
 
 /// Panics if the bridge is paused.
 ///
@@ -26,6 +26,31 @@ pub fn require_supported_chain(config: &BridgeConfig, destination_chain: u32) {
     }
 }
 
+/// Panics if a heartbeat age limit is configured and `chain_id` has not
+/// reported a heartbeat within it.
+pub fn require_fresh_heartbeat(env: &Env, chain_id: u32) {
+    let max_age: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::MaxHeartbeatAge)
+        .unwrap_or(0);
+    if max_age == 0 {
+        return;
+    }
+    let last: Option<u64> = env.storage().persistent().get(&DataKey::ChainHeartbeat(chain_id));
+    match last {
+        Some(at) if env.ledger().timestamp().saturating_sub(at) <= max_age => {}
+        _ => panic!("Chain heartbeat stale"),
+    }
+}
+
+/// Panics if bridging towards `chain_id` has been paused.
+pub fn require_chain_not_paused(env: &Env, chain_id: u32) {
+    if env.storage().persistent().has(&DataKey::ChainPause(chain_id)) {
+        panic!("Chain paused");
+    }
+}
+
 /// Panics if `required_signatures` is outside the configured [min, max] range.
 pub fn require_valid_signatures(config: &BridgeConfig, required_signatures: u32) {
     if required_signatures < config.min_signatures_required
@@ -35,6 +60,110 @@ pub fn require_valid_signatures(config: &BridgeConfig, required_signatures: u32)
     }
 }
 
+/// Panics if `required_signatures` is below the destination chain's
+/// minimum-signature override, when one is set.
+pub fn require_chain_min_signatures(env: &Env, chain_id: u32, required_signatures: u32) {
+    let chain_min: Option<u32> = env
+        .storage()
+        .instance()
+        .get(&DataKey::ChainMinSignatures(chain_id));
+    if let Some(min) = chain_min {
+        if required_signatures < min {
+            panic!("Invalid signature requirement");
+        }
+    }
+}
+
+/// Panics unless `signature` over `message` was produced by `public_key`
+/// under the signature scheme configured for `chain_id`.
+///
+/// Ed25519 keys are 32 bytes. Secp256k1 keys are 65-byte SEC-1 uncompressed
+/// keys; the signature is recovered over the keccak256 digest of `message`
+/// using `recovery_id`, which Ed25519 ignores.
+pub fn require_valid_attestation(
+    env: &Env,
+    chain_id: u32,
+    public_key: &Bytes,
+    message: &Bytes,
+    signature: &BytesN<64>,
+    recovery_id: u32,
+) {
+    let scheme: SignatureScheme = env
+        .storage()
+        .instance()
+        .get(&DataKey::ChainSignatureScheme(chain_id))
+        .unwrap_or_else(|| panic!("Unsupported signature scheme"));
+
+    match scheme {
+        SignatureScheme::Ed25519 => {
+            let key: BytesN<32> = public_key
+                .clone()
+                .try_into()
+                .unwrap_or_else(|_| panic!("Signature scheme mismatch"));
+            env.crypto().ed25519_verify(&key, message, signature);
+        }
+        SignatureScheme::Secp256k1 => {
+            if public_key.len() != 65 {
+                panic!("Signature scheme mismatch");
+            }
+            let digest = env.crypto().keccak256(message);
+            let recovered = env
+                .crypto()
+                .secp256k1_recover(&digest, signature, recovery_id);
+            if Bytes::from(recovered) != *public_key {
+                panic!("Invalid attestation signature");
+            }
+        }
+    }
+}
+
+/// Panics unless a request to `chain_id` may execute with `evidence`. Chains
+/// with a signature scheme require their attester's signature over the
+/// request's `tx_hash`.
+pub fn require_execution_evidence(
+    env: &Env,
+    chain_id: u32,
+    tx_hash: &BytesN<32>,
+    evidence: &Option<ExecutionEvidence>,
+) {
+    if !env.storage().instance().has(&DataKey::ChainSignatureScheme(chain_id)) {
+        return;
+    }
+    let evidence = evidence.as_ref().unwrap_or_else(|| panic!("Attestation required"));
+    let attester: Bytes = env
+        .storage()
+        .instance()
+        .get(&DataKey::ChainAttester(chain_id))
+        .unwrap_or_else(|| panic!("Chain attester not set"));
+    require_valid_attestation(
+        env,
+        chain_id,
+        &attester,
+        &Bytes::from(tx_hash.clone()),
+        &evidence.signature,
+        evidence.recovery_id,
+    );
+}
+
+/// Whether the latest freeze on `request_id` is still in force.
+pub fn is_request_frozen(env: &Env, request_id: u64) -> bool {
+    let freezes: Option<Vec<RequestFreeze>> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RequestFreezes(request_id));
+    matches!(
+        freezes.and_then(|freezes| freezes.last()),
+        Some(freeze) if freeze.released_at.is_none()
+    )
+}
+
+/// Panics if `request_id` is frozen.
+pub fn require_not_frozen(env: &Env, request_id: u64) {
+    if is_request_frozen(env, request_id) {
+        panic!("Request frozen");
+    }
+}
+
 /// Panics if `caller` is not in the operators list.
 pub fn require_operator(env: &Env, caller: &Address) {
     let operators: Vec<Address> = env
@@ -59,9 +188,21 @@ pub fn require_admin(env: &Env, caller: &Address) {
     }
 }
 
+/// Panics unless `caller` is the admin or the configured slash governance.
+pub fn require_slash_authority(env: &Env, caller: &Address) {
+    let governance: Option<Address> = env.storage().instance().get(&DataKey::SlashGovernance);
+    if governance.as_ref() != Some(caller) {
+        require_admin(env, caller);
+    }
+}
+
 /// Panics if `address` is zero (all bytes zero).
 pub fn require_non_zero_address(address: &Address) {
-    if address == &Address::from([0u8; 32]) {
+    let zero = Address::from_string(&String::from_str(
+        address.env(),
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    ));
+    if *address == zero {
         panic!("Zero address not allowed");
     }
 }
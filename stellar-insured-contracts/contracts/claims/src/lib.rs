@@ -1,7 +1,9 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env};
-use stellar_insured_lib::{InsuranceClaim, ClaimStatus, InsurancePolicy, PolicyStatus, PoolStats};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+use stellar_insured_lib::{InsuranceClaim, ClaimStatus, InsurancePolicy, PolicyStatus};
 
 #[contracttype]
 #[derive(Clone)]
@@ -14,6 +16,29 @@ pub enum DataKey {
     /// #409: Maps policy_id -> active claim_id. Present only while a claim is active
     /// (Submitted / UnderReview / Approved). Cleared on Rejected or Settled.
     PolicyActiveClaim(u64),
+    InstallmentPlan(u64),
+    /// Every claim filed against a policy, oldest first.
+    PolicyClaims(u64),
+    /// Hash of the off-chain evidence backing a claim.
+    ClaimEvidence(u64),
+    /// Addresses allowed to approve and reject claims alongside the admin.
+    ClaimProcessor(Address),
+    /// Reason a processor gave for rejecting a claim.
+    RejectionReason(u64),
+    /// Amount settlement pays for an approved claim; at most the claimed amount.
+    ApprovedPayout(u64),
+}
+
+/// Schedule for a claim paid out over several installments.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstallmentPlan {
+    pub num_installments: u32,
+    pub interval_seconds: u64,
+    pub installments_paid: u32,
+    pub amount_paid: i128,
+    pub next_due_at: u64,
+    pub halted: bool,
 }
 
 // --- Storage helpers (#378: data access abstraction) ---
@@ -34,6 +59,81 @@ fn set_claim(env: &Env, claim_id: u64, claim: &InsuranceClaim) {
     env.storage().persistent().set(&DataKey::Claim(claim_id), claim);
 }
 
+fn get_installment_plan(env: &Env, claim_id: u64) -> InstallmentPlan {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InstallmentPlan(claim_id))
+        .expect("No installment plan for claim")
+}
+
+fn require_claim_processor(env: &Env, processor: &Address) {
+    processor.require_auth();
+    let enabled: bool = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ClaimProcessor(processor.clone()))
+        .unwrap_or(false);
+    if !enabled {
+        panic!("Not a claim processor");
+    }
+}
+
+/// Checks a processor may approve `claim` for `payout`: not their own claim,
+/// and the payout within both the amount claimed and the remaining coverage.
+fn require_processor_payout(env: &Env, processor: &Address, claim: &InsuranceClaim, payout: i128) {
+    if claim.claimant == *processor {
+        panic!("Processor cannot approve own claim");
+    }
+
+    let policy_contract: Address = env.storage().instance().get(&DataKey::PolicyContract).unwrap();
+    let policy: InsurancePolicy = env.invoke_contract(
+        &policy_contract,
+        &symbol_short!("get_pol"),
+        (claim.policy_id,).into_val(env),
+    );
+    if payout <= 0 || (payout + policy.total_claimed) > policy.coverage_amount {
+        panic!("Payout invalid or exceeds remaining coverage");
+    }
+    if payout > claim.amount {
+        panic!("Payout exceeds claimed amount");
+    }
+}
+
+fn approve(env: &Env, claim_id: u64, mut claim: InsuranceClaim, payout: i128) {
+    if claim.status != ClaimStatus::UnderReview {
+        panic!("Claim must be under review to approve");
+    }
+
+    claim.status = ClaimStatus::Approved;
+    set_claim(env, claim_id, &claim);
+    env.storage().persistent().set(&DataKey::ApprovedPayout(claim_id), &payout);
+
+    // #412: Enhanced event emission
+    env.events().publish(
+        (symbol_short!("claim"), symbol_short!("approved")),
+        (claim_id, claim.policy_id, payout, claim.claimant),
+    );
+}
+
+fn reject(env: &Env, claim_id: u64) {
+    let mut claim = get_claim_inner(env, claim_id);
+    if claim.status != ClaimStatus::UnderReview {
+        panic!("Claim must be under review to reject");
+    }
+
+    claim.status = ClaimStatus::Rejected;
+    set_claim(env, claim_id, &claim);
+
+    // #409: Clear the active-claim lock so a new claim can be submitted for this policy
+    env.storage().persistent().remove(&DataKey::PolicyActiveClaim(claim.policy_id));
+
+    // #412: Enhanced event emission
+    env.events().publish(
+        (symbol_short!("claim"), symbol_short!("rejected")),
+        (claim_id, claim.policy_id, claim.amount),
+    );
+}
+
 // --------------------------------------------------------
 
 #[contract]
@@ -61,7 +161,7 @@ impl ClaimsContract {
         let is_active: bool = env.invoke_contract(
             &policy_contract,
             &symbol_short!("is_active"),
-            (policy_id,).into(),
+            (policy_id,).into_val(&env),
         );
         if !is_active {
             panic!("Policy is not active or has expired");
@@ -70,7 +170,7 @@ impl ClaimsContract {
         let policy: InsurancePolicy = env.invoke_contract(
             &policy_contract,
             &symbol_short!("get_pol"),
-            (policy_id,).into(),
+            (policy_id,).into_val(&env),
         );
 
         // Consistency check: claim amount must not exceed coverage
@@ -93,7 +193,7 @@ impl ClaimsContract {
         let claim = InsuranceClaim {
             claim_id: counter,
             policy_id,
-            claimant,
+            claimant: claimant.clone(),
             amount,
             status: ClaimStatus::Submitted,
             submitted_at: env.ledger().timestamp(),
@@ -104,6 +204,14 @@ impl ClaimsContract {
         // #409: Record the active claim for this policy (O(1) dedup key)
         env.storage().persistent().set(&DataKey::PolicyActiveClaim(policy_id), &counter);
 
+        let mut policy_claims: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PolicyClaims(policy_id))
+            .unwrap_or(Vec::new(&env));
+        policy_claims.push_back(counter);
+        env.storage().persistent().set(&DataKey::PolicyClaims(policy_id), &policy_claims);
+
         // #412: Enhanced event emission with more details
         env.events().publish(
             (symbol_short!("claim"), symbol_short!("submitted")),
@@ -113,6 +221,20 @@ impl ClaimsContract {
         counter
     }
 
+    /// Submits a claim together with the hash of its off-chain evidence.
+    pub fn submit_claim_with_evidence(env: Env, policy_id: u64, amount: i128, evidence_hash: BytesN<32>) -> u64 {
+        if evidence_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid evidence hash");
+        }
+
+        let claim_id = Self::submit_claim(env.clone(), policy_id, amount);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimEvidence(claim_id), &evidence_hash);
+
+        claim_id
+    }
+
     pub fn start_review(env: Env, claim_id: u64) {
         let admin = get_admin(&env);
         admin.require_auth();
@@ -136,40 +258,189 @@ impl ClaimsContract {
         let admin = get_admin(&env);
         admin.require_auth();
 
+        let claim = get_claim_inner(&env, claim_id);
+        let payout = claim.amount;
+        approve(&env, claim_id, claim, payout);
+    }
+
+    pub fn set_claim_processor(env: Env, processor: Address, enabled: bool) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimProcessor(processor.clone()), &enabled);
+
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("processor")),
+            (processor, enabled),
+        );
+    }
+
+    /// Approves a claim on behalf of a claim processor, fixing the amount
+    /// that settlement will pay out, which may be less than the amount
+    /// claimed. Processors cannot approve their own claims.
+    pub fn process_approve_claim(env: Env, processor: Address, claim_id: u64, payout: i128) {
+        require_claim_processor(&env, &processor);
+
+        let claim = get_claim_inner(&env, claim_id);
+        require_processor_payout(&env, &processor, &claim, payout);
+
+        approve(&env, claim_id, claim, payout);
+    }
+
+    /// Approves a claim on behalf of a claim processor for `payout`, paid in
+    /// `num_installments` equal parts, the first due immediately and each
+    /// later one `interval_seconds` apart. The payout is validated as in
+    /// `process_approve_claim`.
+    pub fn approve_installment_claim(
+        env: Env,
+        processor: Address,
+        claim_id: u64,
+        payout: i128,
+        num_installments: u32,
+        interval_seconds: u64,
+    ) {
+        require_claim_processor(&env, &processor);
+
+        if num_installments == 0 || interval_seconds == 0 {
+            panic!("Invalid installment schedule");
+        }
+
+        let claim = get_claim_inner(&env, claim_id);
+        require_processor_payout(&env, &processor, &claim, payout);
+        if (num_installments as i128) > payout {
+            panic!("Invalid installment schedule");
+        }
+
+        approve(&env, claim_id, claim, payout);
+
+        let plan = InstallmentPlan {
+            num_installments,
+            interval_seconds,
+            installments_paid: 0,
+            amount_paid: 0,
+            next_due_at: env.ledger().timestamp(),
+            halted: false,
+        };
+        env.storage().persistent().set(&DataKey::InstallmentPlan(claim_id), &plan);
+
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("instplan")),
+            (claim_id, payout, num_installments, interval_seconds),
+        );
+    }
+
+    /// Pays the next due installment from the risk pool. Callable by anyone
+    /// once it is due; the claim settles with the final installment.
+    pub fn release_claim_installment(env: Env, claim_id: u64) -> i128 {
         let mut claim = get_claim_inner(&env, claim_id);
-        if claim.status != ClaimStatus::UnderReview {
-            panic!("Claim must be under review to approve");
+        let mut plan = get_installment_plan(&env, claim_id);
+
+        if plan.halted {
+            panic!("Installments halted");
+        }
+        if claim.status != ClaimStatus::Approved {
+            panic!("Claim is not approved");
+        }
+        let now = env.ledger().timestamp();
+        if now < plan.next_due_at {
+            panic!("Installment not yet due");
         }
 
-        claim.status = ClaimStatus::Approved;
-        set_claim(&env, claim_id, &claim);
+        let payout: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ApprovedPayout(claim_id))
+            .unwrap_or(claim.amount);
+        let is_last = plan.installments_paid + 1 == plan.num_installments;
+        let amount = if is_last {
+            payout - plan.amount_paid
+        } else {
+            payout / plan.num_installments as i128
+        };
+
+        let risk_pool: Address = env.storage().instance().get(&DataKey::RiskPool).unwrap();
+        env.invoke_contract::<()>(
+            &risk_pool,
+            &symbol_short!("pay_claim"),
+            (env.current_contract_address(), claim.claimant.clone(), amount).into_val(&env),
+        );
+
+        let policy_contract: Address = env.storage().instance().get(&DataKey::PolicyContract).unwrap();
+        env.invoke_contract::<()>(
+            &policy_contract,
+            &symbol_short!("update_cl"),
+            (claim.policy_id, amount).into_val(&env),
+        );
+
+        plan.installments_paid += 1;
+        plan.amount_paid += amount;
+        plan.next_due_at = now + plan.interval_seconds;
+        env.storage().persistent().set(&DataKey::InstallmentPlan(claim_id), &plan);
+
+        if is_last {
+            claim.status = ClaimStatus::Settled;
+            set_claim(&env, claim_id, &claim);
+            env.storage().persistent().remove(&DataKey::PolicyActiveClaim(claim.policy_id));
+        }
 
-        // #412: Enhanced event emission
         env.events().publish(
-            (symbol_short!("claim"), symbol_short!("approved")),
-            (claim_id, claim.policy_id, claim.amount, claim.claimant),
+            (symbol_short!("claim"), symbol_short!("install")),
+            (claim_id, plan.installments_paid, amount),
         );
+
+        amount
     }
 
-    pub fn reject_claim(env: Env, claim_id: u64) {
-        let admin = get_admin(&env);
-        admin.require_auth();
+    /// Stops further installments, e.g. when fraud is discovered mid-stream,
+    /// and closes the claim as rejected. Installments already paid are not
+    /// reversed. Callable by the admin or a claim processor.
+    pub fn halt_installments(env: Env, caller: Address, claim_id: u64) {
+        if caller == get_admin(&env) {
+            caller.require_auth();
+        } else {
+            require_claim_processor(&env, &caller);
+        }
 
         let mut claim = get_claim_inner(&env, claim_id);
-        if claim.status != ClaimStatus::UnderReview {
-            panic!("Claim must be under review to reject");
+        let mut plan = get_installment_plan(&env, claim_id);
+        if claim.status != ClaimStatus::Approved {
+            panic!("Claim is not approved");
         }
 
+        plan.halted = true;
+        env.storage().persistent().set(&DataKey::InstallmentPlan(claim_id), &plan);
+
         claim.status = ClaimStatus::Rejected;
         set_claim(&env, claim_id, &claim);
-
-        // #409: Clear the active-claim lock so a new claim can be submitted for this policy
         env.storage().persistent().remove(&DataKey::PolicyActiveClaim(claim.policy_id));
 
-        // #412: Enhanced event emission
         env.events().publish(
-            (symbol_short!("claim"), symbol_short!("rejected")),
-            (claim_id, claim.policy_id, claim.amount),
+            (symbol_short!("claim"), symbol_short!("insthalt")),
+            (claim_id, claim.policy_id, plan.installments_paid, plan.amount_paid),
+        );
+    }
+
+    pub fn reject_claim(env: Env, claim_id: u64) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        reject(&env, claim_id);
+    }
+
+    /// Rejects a claim on behalf of a claim processor, recording the reason.
+    pub fn process_reject_claim(env: Env, processor: Address, claim_id: u64, reason: Symbol) {
+        require_claim_processor(&env, &processor);
+
+        reject(&env, claim_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RejectionReason(claim_id), &reason);
+
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("rejreason")),
+            (claim_id, processor, reason),
         );
     }
 
@@ -181,36 +452,30 @@ impl ClaimsContract {
         if claim.status != ClaimStatus::Approved {
             panic!("Only approved claims can be settled");
         }
-
-        // #410: Check risk pool balance before payout
-        let risk_pool: Address = env.storage().instance().get(&DataKey::RiskPool).unwrap();
-        
-        // Get pool stats to verify available capital
-        let pool_stats: PoolStats = env.invoke_contract(
-            &risk_pool,
-            &symbol_short!("get_stats"),
-            ().into(),
-        );
-        
-        if pool_stats.available_capital < claim.amount {
-            panic!("Insufficient risk pool funds for payout");
+        if env.storage().persistent().has(&DataKey::InstallmentPlan(claim_id)) {
+            panic!("Installment claims settle through installments");
         }
-
-        // Cross-contract call to Risk Pool to payout
-        // payout_claim(recipient, amount)
+        let payout: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ApprovedPayout(claim_id))
+            .unwrap_or(claim.amount);
+
+        // #410: The risk pool checks its available capital before paying out,
+        // and only pays contracts it has registered as trusted.
         let risk_pool: Address = env.storage().instance().get(&DataKey::RiskPool).unwrap();
-
         env.invoke_contract::<()>(
             &risk_pool,
-            &symbol_short!("payout"),
-            (claim.claimant.clone(), claim.amount).into(),
+            &symbol_short!("pay_claim"),
+            (env.current_contract_address(), claim.claimant.clone(), payout).into_val(&env),
         );
 
         // Update total claimed in policy contract
+        let policy_contract: Address = env.storage().instance().get(&DataKey::PolicyContract).unwrap();
         env.invoke_contract::<()>(
             &policy_contract,
             &symbol_short!("update_cl"),
-            (claim.policy_id, claim.amount).into(),
+            (claim.policy_id, payout).into_val(&env),
         );
 
         claim.status = ClaimStatus::Settled;
@@ -222,7 +487,7 @@ impl ClaimsContract {
         // #412: Enhanced event emission
         env.events().publish(
             (symbol_short!("claim"), symbol_short!("settled")),
-            (claim_id, claim.amount, claim.claimant),
+            (claim_id, payout, claim.claimant),
         );
     }
 }
@@ -236,4 +501,37 @@ impl ClaimsContract {
     pub fn get_stats(env: Env) -> u64 {
         get_claim_counter(&env)
     }
+
+    pub fn get_claims_by_policy(env: Env, policy_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PolicyClaims(policy_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_claim_evidence(env: Env, claim_id: u64) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&DataKey::ClaimEvidence(claim_id))
+    }
+
+    pub fn is_claim_processor(env: Env, processor: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ClaimProcessor(processor))
+            .unwrap_or(false)
+    }
+
+    pub fn get_rejection_reason(env: Env, claim_id: u64) -> Option<Symbol> {
+        env.storage().persistent().get(&DataKey::RejectionReason(claim_id))
+    }
+
+    pub fn get_approved_payout(env: Env, claim_id: u64) -> Option<i128> {
+        env.storage().persistent().get(&DataKey::ApprovedPayout(claim_id))
+    }
+
+    pub fn get_installment_plan(env: Env, claim_id: u64) -> Option<InstallmentPlan> {
+        env.storage().persistent().get(&DataKey::InstallmentPlan(claim_id))
+    }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,321 @@
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, vec, BytesN, Env};
+use stellar_insured_lib::PolicyType;
+
+#[contract]
+struct MockPolicy;
+
+#[contractimpl]
+impl MockPolicy {
+    pub fn is_active(_env: Env, _policy_id: u64) -> bool {
+        true
+    }
+
+    pub fn get_pol(env: Env, policy_id: u64) -> InsurancePolicy {
+        let holder: Address = env.storage().instance().get(&symbol_short!("holder")).unwrap();
+        InsurancePolicy {
+            policy_id,
+            holder,
+            coverage_amount: 10_000,
+            premium_amount: 100,
+            start_time: 0,
+            duration_days: 30,
+            policy_type: PolicyType::Standard,
+            status: PolicyStatus::Active,
+            risk_pool: env.current_contract_address(),
+            total_claimed: 0,
+        }
+    }
+
+    pub fn set_holder(env: Env, holder: Address) {
+        env.storage().instance().set(&symbol_short!("holder"), &holder);
+    }
+
+    pub fn update_cl(_env: Env, _policy_id: u64, _amount: i128) {}
+}
+
+#[contract]
+struct MockPool;
+
+#[contractimpl]
+impl MockPool {
+    pub fn register_trusted_contract(env: Env, contract: Address) {
+        env.storage().instance().set(&(symbol_short!("trusted"), contract), &true);
+    }
+
+    pub fn pay_claim(env: Env, caller_contract: Address, claimant: Address, amount: i128) {
+        caller_contract.require_auth();
+        if !env.storage().instance().has(&(symbol_short!("trusted"), caller_contract)) {
+            panic!("Not a trusted contract");
+        }
+        let paid: i128 = env.storage().instance().get(&claimant).unwrap_or(0);
+        env.storage().instance().set(&claimant, &(paid + amount));
+    }
+
+    pub fn paid(env: Env, recipient: Address) -> i128 {
+        env.storage().instance().get(&recipient).unwrap_or(0)
+    }
+}
+
+fn setup(env: &Env) -> (ClaimsContractClient<'_>, MockPoolClient<'_>, Address) {
+    let (client, pool, holder) = setup_untrusted(env);
+    pool.register_trusted_contract(&client.address);
+    (client, pool, holder)
+}
+
+fn setup_untrusted(env: &Env) -> (ClaimsContractClient<'_>, MockPoolClient<'_>, Address) {
+    env.mock_all_auths();
+    let policy_id = env.register_contract(None, MockPolicy);
+    let pool_id = env.register_contract(None, MockPool);
+    let contract_id = env.register_contract(None, ClaimsContract);
+    let client = ClaimsContractClient::new(env, &contract_id);
+
+    let holder = Address::generate(env);
+    MockPolicyClient::new(env, &policy_id).set_holder(&holder);
+    client.initialize(&Address::generate(env), &policy_id, &pool_id);
+    (client, MockPoolClient::new(env, &pool_id), holder)
+}
+
+fn installment_claim(env: &Env, client: &ClaimsContractClient<'_>, amount: i128) -> u64 {
+    let processor = Address::generate(env);
+    client.set_claim_processor(&processor, &true);
+    let claim_id = client.submit_claim(&1, &amount);
+    client.start_review(&claim_id);
+    client.approve_installment_claim(&processor, &claim_id, &amount, &3, &1_000);
+    claim_id
+}
+
+#[test]
+fn test_installments_released_on_schedule() {
+    let env = Env::default();
+    let (client, pool, holder) = setup(&env);
+    let claim_id = installment_claim(&env, &client, 1_000);
+
+    assert_eq!(client.release_claim_installment(&claim_id), 333);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(client.release_claim_installment(&claim_id), 333);
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.release_claim_installment(&claim_id), 334);
+
+    assert_eq!(pool.paid(&holder), 1_000);
+    assert_eq!(client.get_claim(&claim_id).status, ClaimStatus::Settled);
+    assert_eq!(client.get_installment_plan(&claim_id).unwrap().installments_paid, 3);
+}
+
+#[test]
+fn test_installments_pay_approved_payout() {
+    let env = Env::default();
+    let (client, pool, holder) = setup(&env);
+    let processor = Address::generate(&env);
+    client.set_claim_processor(&processor, &true);
+    let claim_id = client.submit_claim(&1, &1_000);
+    client.start_review(&claim_id);
+
+    client.approve_installment_claim(&processor, &claim_id, &900, &3, &1_000);
+    assert_eq!(client.get_approved_payout(&claim_id), Some(900));
+    assert_eq!(client.release_claim_installment(&claim_id), 300);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.release_claim_installment(&claim_id);
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.release_claim_installment(&claim_id);
+    assert_eq!(pool.paid(&holder), 900);
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds claimed amount")]
+fn test_installment_payout_over_claim_amount_rejected() {
+    let env = Env::default();
+    let (client, processor, claim_id) = processor_setup(&env);
+    client.approve_installment_claim(&processor, &claim_id, &4_001, &3, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Processor cannot approve own claim")]
+fn test_processor_cannot_approve_own_installment_claim() {
+    let env = Env::default();
+    let (client, _pool, holder) = setup(&env);
+    client.set_claim_processor(&holder, &true);
+    let claim_id = client.submit_claim(&1, &1_000);
+    client.start_review(&claim_id);
+    client.approve_installment_claim(&holder, &claim_id, &1_000, &3, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Not a claim processor")]
+fn test_installment_approval_requires_processor() {
+    let env = Env::default();
+    let (client, _pool, _holder) = setup(&env);
+    let claim_id = client.submit_claim(&1, &1_000);
+    client.start_review(&claim_id);
+    client.approve_installment_claim(&Address::generate(&env), &claim_id, &1_000, &3, &1_000);
+}
+
+#[test]
+fn test_settle_pays_claimant_through_pool() {
+    let env = Env::default();
+    let (client, pool, holder) = setup(&env);
+    let claim_id = client.submit_claim(&1, &800);
+    client.start_review(&claim_id);
+    client.approve_claim(&claim_id);
+
+    client.settle_claim(&claim_id);
+    assert_eq!(pool.paid(&holder), 800);
+    assert_eq!(client.get_claim(&claim_id).status, ClaimStatus::Settled);
+}
+
+#[test]
+#[should_panic(expected = "Not a trusted contract")]
+fn test_settle_from_untrusted_claims_contract_rejected() {
+    let env = Env::default();
+    let (client, _pool, _holder) = setup_untrusted(&env);
+    let claim_id = client.submit_claim(&1, &800);
+    client.start_review(&claim_id);
+    client.approve_claim(&claim_id);
+    client.settle_claim(&claim_id);
+}
+
+#[test]
+#[should_panic(expected = "Installment not yet due")]
+fn test_installment_not_released_early() {
+    let env = Env::default();
+    let (client, _pool, _holder) = setup(&env);
+    let claim_id = installment_claim(&env, &client, 1_000);
+
+    client.release_claim_installment(&claim_id);
+    env.ledger().with_mut(|li| li.timestamp = 999);
+    client.release_claim_installment(&claim_id);
+}
+
+#[test]
+#[should_panic(expected = "Installments halted")]
+fn test_halted_installments_stop_paying() {
+    let env = Env::default();
+    let (client, pool, holder) = setup(&env);
+    let claim_id = installment_claim(&env, &client, 1_000);
+
+    let processor = Address::generate(&env);
+    client.set_claim_processor(&processor, &true);
+
+    client.release_claim_installment(&claim_id);
+    client.halt_installments(&processor, &claim_id);
+    assert_eq!(pool.paid(&holder), 333);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.release_claim_installment(&claim_id);
+}
+
+#[test]
+fn test_halting_installments_closes_claim() {
+    let env = Env::default();
+    let (client, _pool, _holder) = setup(&env);
+    let claim_id = installment_claim(&env, &client, 1_000);
+    let processor = Address::generate(&env);
+    client.set_claim_processor(&processor, &true);
+
+    client.halt_installments(&processor, &claim_id);
+    assert_eq!(client.get_claim(&claim_id).status, ClaimStatus::Rejected);
+    assert!(client.get_installment_plan(&claim_id).unwrap().halted);
+
+    let next = client.submit_claim(&1, &500);
+    assert_eq!(client.get_claim(&next).status, ClaimStatus::Submitted);
+}
+
+#[test]
+#[should_panic(expected = "Installment claims settle through installments")]
+fn test_installment_claim_cannot_settle_in_full() {
+    let env = Env::default();
+    let (client, _pool, _holder) = setup(&env);
+    let claim_id = installment_claim(&env, &client, 1_000);
+    client.settle_claim(&claim_id);
+}
+
+#[test]
+fn test_claims_indexed_by_policy_with_evidence() {
+    let env = Env::default();
+    let (client, _pool, _holder) = setup(&env);
+    let evidence = BytesN::from_array(&env, &[7u8; 32]);
+
+    let first = client.submit_claim_with_evidence(&1, &400, &evidence);
+    assert_eq!(client.get_claim_evidence(&first), Some(evidence));
+    client.start_review(&first);
+    client.reject_claim(&first);
+    let second = client.submit_claim(&1, &300);
+
+    assert_eq!(client.get_claims_by_policy(&1), vec![&env, first, second]);
+    assert_eq!(client.get_claims_by_policy(&2).len(), 0);
+    assert_eq!(client.get_claim_evidence(&second), None);
+}
+
+#[test]
+#[should_panic(expected = "Invalid evidence hash")]
+fn test_zero_evidence_hash_rejected() {
+    let env = Env::default();
+    let (client, _pool, _holder) = setup(&env);
+    client.submit_claim_with_evidence(&1, &400, &BytesN::from_array(&env, &[0u8; 32]));
+}
+
+fn processor_setup(env: &Env) -> (ClaimsContractClient<'_>, Address, u64) {
+    let (client, _pool, _holder) = setup(env);
+    let processor = Address::generate(env);
+    client.set_claim_processor(&processor, &true);
+    let claim_id = client.submit_claim(&1, &4_000);
+    client.start_review(&claim_id);
+    (client, processor, claim_id)
+}
+
+#[test]
+fn test_processor_approves_claim_with_payout() {
+    let env = Env::default();
+    let (client, pool, holder) = setup(&env);
+    let processor = Address::generate(&env);
+    client.set_claim_processor(&processor, &true);
+    let claim_id = client.submit_claim(&1, &4_000);
+    client.start_review(&claim_id);
+
+    client.process_approve_claim(&processor, &claim_id, &3_500);
+    let claim = client.get_claim(&claim_id);
+    assert_eq!(claim.status, ClaimStatus::Approved);
+    assert_eq!(claim.amount, 4_000);
+    assert_eq!(client.get_approved_payout(&claim_id), Some(3_500));
+
+    client.settle_claim(&claim_id);
+    assert_eq!(pool.paid(&holder), 3_500);
+}
+
+#[test]
+fn test_processor_rejects_claim_with_reason() {
+    let env = Env::default();
+    let (client, processor, claim_id) = processor_setup(&env);
+
+    client.process_reject_claim(&processor, &claim_id, &symbol_short!("fraud"));
+    assert_eq!(client.get_claim(&claim_id).status, ClaimStatus::Rejected);
+    assert_eq!(client.get_rejection_reason(&claim_id), Some(symbol_short!("fraud")));
+}
+
+#[test]
+#[should_panic(expected = "Payout invalid or exceeds remaining coverage")]
+fn test_processor_payout_over_coverage_rejected() {
+    let env = Env::default();
+    let (client, processor, claim_id) = processor_setup(&env);
+    client.process_approve_claim(&processor, &claim_id, &10_001);
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds claimed amount")]
+fn test_processor_payout_over_claim_amount_rejected() {
+    let env = Env::default();
+    let (client, processor, claim_id) = processor_setup(&env);
+    client.process_approve_claim(&processor, &claim_id, &4_001);
+}
+
+#[test]
+#[should_panic(expected = "Processor cannot approve own claim")]
+fn test_processor_cannot_approve_own_claim() {
+    let env = Env::default();
+    let (client, _pool, holder) = setup(&env);
+    client.set_claim_processor(&holder, &true);
+    let claim_id = client.submit_claim(&1, &4_000);
+    client.start_review(&claim_id);
+    client.process_approve_claim(&holder, &claim_id, &4_000);
+}
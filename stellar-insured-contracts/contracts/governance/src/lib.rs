@@ -1,7 +1,11 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String, Vec, Symbol};
-use stellar_insured_lib::{Proposal, GovernanceAction};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env, IntoVal, String, Vec, Symbol};
+use stellar_insured_lib::upgradeable::{self, VersionInfo, VersionTransition};
+use stellar_insured_lib::{Proposal, GovernanceAction, Upgradeable};
+
+const CONTRACT_VERSION: u32 = 1;
+const MAX_PAGINATION_LIMIT: u32 = 50;
 
 #[contracttype]
 #[derive(Clone)]
@@ -17,6 +21,13 @@ pub enum DataKey {
     VoterRecord(u64, Address),
     VotingPeriod,
     GovernanceActionPending(u64),  // proposal_id -> GovernanceAction
+    VoterReputation(Address),
+    OutcomeRecorded(u64, Address),
+    ReputationWeights,
+    ReputationRecorder,
+    QuorumConfig,  // (min_quorum_votes, approval_threshold_pct)
+    /// Whether a proposal passed, fixed when it is finalized.
+    ProposalPassed(u64),
 }
 
 #[contracttype]
@@ -59,6 +70,36 @@ fn set_proposal(env: &Env, proposal_id: u64, proposal: &Proposal) {
     env.storage().persistent().set(&DataKey::Proposal(proposal_id), proposal);
 }
 
+fn get_quorum_config(env: &Env) -> (i128, u32) {
+    env.storage().instance().get(&DataKey::QuorumConfig).unwrap_or((0, 0))
+}
+
+/// A proposal passes when turnout meets the quorum and the yes share meets
+/// the stricter of its own threshold and the contract-wide threshold.
+fn proposal_passed(env: &Env, proposal: &Proposal) -> bool {
+    let (min_quorum_votes, approval_threshold_pct) = get_quorum_config(env);
+    let threshold = proposal.threshold_percentage.max(approval_threshold_pct);
+    let total_votes = proposal.yes_votes + proposal.no_votes;
+    total_votes > 0
+        && total_votes >= min_quorum_votes
+        && (proposal.yes_votes * 100 / total_votes) >= threshold as i128
+}
+
+/// Voting power is the voter's balance of the governance token.
+fn get_voting_power(env: &Env, voter: &Address) -> i128 {
+    let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    token::Client::new(env, &token).balance(voter)
+}
+
+/// Outcome stored by `finalize_proposal`; later quorum changes do not affect it.
+fn finalized_outcome(env: &Env, proposal_id: u64) -> bool {
+    env.storage().persistent().get(&DataKey::ProposalPassed(proposal_id)).unwrap_or(false)
+}
+
+fn get_reputation(env: &Env, voter: &Address) -> i128 {
+    env.storage().persistent().get(&DataKey::VoterReputation(voter.clone())).unwrap_or(0)
+}
+
 // --------------------------------------------------------
 
 #[contract]
@@ -87,6 +128,7 @@ impl GovernanceContract {
         env.storage().instance().set(&DataKey::ClaimsContract, &claims_contract);
         env.storage().instance().set(&DataKey::RiskPoolContract, &risk_pool_contract);
         env.storage().instance().set(&DataKey::PolicyContract, &policy_contract);
+        upgradeable::init_version(&env, CONTRACT_VERSION);
 
         // #379: emit event for initialization
         env.events().publish(
@@ -219,7 +261,7 @@ impl GovernanceContract {
         env.storage().persistent().set(&DataKey::GovernanceActionPending(counter), &action);
 
         env.events().publish(
-            (symbol_short!("gov"), symbol_short!("claim_prop")),
+            (symbol_short!("gov"), symbol_short!("claimprop")),
             (counter, claim_id, creator),
         );
 
@@ -264,7 +306,7 @@ impl GovernanceContract {
         set_proposal(&env, counter, &proposal);
 
         // Store the governance action
-        let action = GovernanceAction::FundAllocation(recipient, amount);
+        let action = GovernanceAction::FundAllocation(recipient.clone(), amount);
         env.storage().persistent().set(&DataKey::GovernanceActionPending(counter), &action);
 
         env.events().publish(
@@ -311,19 +353,76 @@ impl GovernanceContract {
         );
     }
 
+    /// Replaces a voter's earlier vote while voting is still open, moving
+    /// their weight between sides without counting them twice. The new
+    /// weight must be positive and within the voter's token balance.
+    pub fn change_vote(env: Env, voter: Address, proposal_id: u64, new_weight: i128, new_is_yes: bool) {
+        voter.require_auth();
+
+        if new_weight <= 0 {
+            panic!("Vote weight must be positive");
+        }
+        if new_weight > get_voting_power(&env, &voter) {
+            panic!("Vote weight exceeds voting power");
+        }
+
+        let mut proposal = get_proposal_inner(&env, proposal_id);
+
+        if env.ledger().timestamp() > proposal.expires_at {
+            panic!("Voting period ended");
+        }
+
+        let record_key = DataKey::VoterRecord(proposal_id, voter.clone());
+        let mut record: VoteRecord = env
+            .storage()
+            .persistent()
+            .get(&record_key)
+            .unwrap_or_else(|| panic!("No vote to change"));
+
+        if record.is_yes {
+            proposal.yes_votes -= record.weight;
+        } else {
+            proposal.no_votes -= record.weight;
+        }
+        if new_is_yes {
+            proposal.yes_votes += new_weight;
+        } else {
+            proposal.no_votes += new_weight;
+        }
+
+        record.weight = new_weight;
+        record.is_yes = new_is_yes;
+        record.timestamp = env.ledger().timestamp();
+
+        set_proposal(&env, proposal_id, &proposal);
+        env.storage().persistent().set(&record_key, &record);
+
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("revote")),
+            (proposal_id, voter),
+        );
+    }
+
+    /// Closes voting and records whether the proposal passed under the
+    /// quorum configuration in force at this point.
     pub fn finalize_proposal(env: Env, proposal_id: u64) {
         let mut proposal = get_proposal_inner(&env, proposal_id);
 
         if env.ledger().timestamp() <= proposal.expires_at {
             panic!("Voting period not yet ended");
         }
+        if proposal.is_finalized {
+            panic!("Already finalized");
+        }
 
+        let passed = proposal_passed(&env, &proposal);
         proposal.is_finalized = true;
         set_proposal(&env, proposal_id, &proposal);
+        env.storage().persistent().set(&DataKey::ProposalPassed(proposal_id), &passed);
 
         env.events().publish(
             (symbol_short!("gov"), symbol_short!("final")),
-            proposal_id,
+            (proposal_id, passed),
         );
     }
 
@@ -338,8 +437,7 @@ impl GovernanceContract {
             panic!("Already executed");
         }
 
-        let total_votes = proposal.yes_votes + proposal.no_votes;
-        if total_votes == 0 || (proposal.yes_votes * 100 / total_votes) < proposal.threshold_percentage as i128 {
+        if !finalized_outcome(&env, proposal_id) {
             panic!("Threshold not met");
         }
 
@@ -356,7 +454,7 @@ impl GovernanceContract {
                     env.invoke_contract::<()>(
                         &claims_contract,
                         &symbol_short!("approve"),
-                        (claim_id,).into(),
+                        (claim_id,).into_val(&env),
                     );
                 }
                 GovernanceAction::FundAllocation(recipient, amount) => {
@@ -366,7 +464,7 @@ impl GovernanceContract {
                     env.invoke_contract::<()>(
                         &risk_pool,
                         &symbol_short!("payout"),
-                        (recipient, amount).into(),
+                        (recipient, amount).into_val(&env),
                     );
                 }
                 GovernanceAction::PolicyChange(policy_id) => {
@@ -376,7 +474,7 @@ impl GovernanceContract {
                     env.invoke_contract::<()>(
                         &policy_contract,
                         &symbol_short!("update"),
-                        (policy_id,).into(),
+                        (policy_id,).into_val(&env),
                     );
                 }
             }
@@ -399,6 +497,91 @@ impl GovernanceContract {
     pub fn execute_slashing_proposal(env: Env, proposal_id: u64) {
         Self::execute_proposal(env, proposal_id);
     }
+
+    /// Sets the minimum total vote weight a proposal needs to pass and the
+    /// approval threshold applied on top of each proposal's own threshold.
+    pub fn set_quorum(env: Env, min_quorum_votes: i128, approval_threshold_pct: u32) {
+        get_admin(&env).require_auth();
+        if min_quorum_votes < 0 {
+            panic!("Quorum cannot be negative");
+        }
+        if !(51..=100).contains(&approval_threshold_pct) {
+            panic!("Approval threshold must be between 51 and 100");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::QuorumConfig, &(min_quorum_votes, approval_threshold_pct));
+
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("quorum")),
+            (min_quorum_votes, approval_threshold_pct),
+        );
+    }
+
+    /// Enables reputation tracking: `recorder` is the only caller allowed to
+    /// score votes, and a voter's reputation rises by `aligned_reward` when
+    /// they voted with a finalized outcome and falls by `misaligned_penalty`
+    /// when they voted against it.
+    pub fn set_reputation_weights(env: Env, recorder: Address, aligned_reward: i128, misaligned_penalty: i128) {
+        get_admin(&env).require_auth();
+        if aligned_reward < 0 || misaligned_penalty < 0 {
+            panic!("Reputation weights must be non-negative");
+        }
+        env.storage().instance().set(&DataKey::ReputationRecorder, &recorder);
+        env.storage()
+            .instance()
+            .set(&DataKey::ReputationWeights, &(aligned_reward, misaligned_penalty));
+
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("repweight")),
+            (recorder, aligned_reward, misaligned_penalty),
+        );
+    }
+
+    /// Adjusts `voter`'s reputation by whether their vote matched the outcome
+    /// of a finalized proposal. Callable only by the configured recorder once
+    /// tracking is enabled. Each vote is scored once. Returns the new score.
+    pub fn record_vote_outcome(env: Env, voter: Address, proposal_id: u64) -> i128 {
+        let (aligned_reward, misaligned_penalty): (i128, i128) = env
+            .storage()
+            .instance()
+            .get(&DataKey::ReputationWeights)
+            .unwrap_or_else(|| panic!("Reputation tracking not enabled"));
+        let recorder: Address = env.storage().instance().get(&DataKey::ReputationRecorder).unwrap();
+        recorder.require_auth();
+
+        let proposal = get_proposal_inner(&env, proposal_id);
+        if !proposal.is_finalized {
+            panic!("Proposal must be finalized first");
+        }
+
+        let record: VoteRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VoterRecord(proposal_id, voter.clone()))
+            .expect("Vote not found");
+
+        let recorded_key = DataKey::OutcomeRecorded(proposal_id, voter.clone());
+        if env.storage().persistent().has(&recorded_key) {
+            panic!("Outcome already recorded");
+        }
+        env.storage().persistent().set(&recorded_key, &true);
+
+        let aligned = record.is_yes == finalized_outcome(&env, proposal_id);
+        let reputation = if aligned {
+            get_reputation(&env, &voter) + aligned_reward
+        } else {
+            get_reputation(&env, &voter) - misaligned_penalty
+        };
+        env.storage().persistent().set(&DataKey::VoterReputation(voter.clone()), &reputation);
+
+        env.events().publish(
+            (symbol_short!("gov"), symbol_short!("outcome")),
+            (proposal_id, voter, aligned, reputation),
+        );
+
+        reputation
+    }
 }
 
 #[contractimpl]
@@ -426,6 +609,8 @@ impl GovernanceContract {
         let now = env.ledger().timestamp();
         let status = if p.is_executed {
             symbol_short!("executed")
+        } else if p.is_finalized && !finalized_outcome(&env, proposal_id) {
+            symbol_short!("rejected")
         } else if p.is_finalized {
             symbol_short!("finalized")
         } else if now > p.expires_at {
@@ -442,6 +627,25 @@ impl GovernanceContract {
         }
     }
 
+    /// Returns up to `limit` proposals (capped at 50) starting at index
+    /// `start` in creation order, together with the total proposal count.
+    /// Out-of-range pages are empty.
+    pub fn get_proposals_paginated(env: Env, start: u32, limit: u32) -> (Vec<Proposal>, u32) {
+        let total = get_proposal_counter(&env) as u32;
+        let end = start.saturating_add(limit.min(MAX_PAGINATION_LIMIT)).min(total);
+
+        let mut page = Vec::new(&env);
+        for i in start..end {
+            page.push_back(get_proposal_inner(&env, i as u64 + 1));
+        }
+        (page, total)
+    }
+
+    /// Returns `(min_quorum_votes, approval_threshold_pct)`; zeros when unset.
+    pub fn get_quorum_config(env: Env) -> (i128, u32) {
+        get_quorum_config(&env)
+    }
+
     pub fn get_all_proposals(env: Env) -> Vec<u64> {
         let counter = get_proposal_counter(&env);
         let mut list = Vec::new(&env);
@@ -451,7 +655,39 @@ impl GovernanceContract {
         list
     }
 
+    pub fn get_voter_reputation(env: Env, voter: Address) -> i128 {
+        get_reputation(&env, &voter)
+    }
+
     pub fn get_vote_record(env: Env, proposal_id: u64, voter: Address) -> Option<VoteRecord> {
         env.storage().persistent().get(&DataKey::VoterRecord(proposal_id, voter))
     }
 }
+
+#[contractimpl]
+impl Upgradeable for GovernanceContract {
+    fn contract_version(env: Env) -> u32 {
+        upgradeable::contract_version(&env)
+    }
+
+    fn upgrade(env: Env, new_version: u32) {
+        get_admin(&env).require_auth();
+        upgradeable::upgrade(&env, new_version);
+    }
+
+    fn rollback(env: Env, target_version: u32) {
+        get_admin(&env).require_auth();
+        upgradeable::rollback(&env, target_version);
+    }
+
+    fn version_info(env: Env) -> VersionInfo {
+        upgradeable::version_info(&env)
+    }
+
+    fn version_history(env: Env) -> Vec<VersionTransition> {
+        upgradeable::version_history(&env)
+    }
+}
+
+#[cfg(test)]
+mod tests;
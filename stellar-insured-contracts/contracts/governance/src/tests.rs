@@ -0,0 +1,277 @@
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, token, Env, String};
+
+fn setup(env: &Env) -> (GovernanceContractClient<'_>, Address) {
+    let (client, admin, _token) = setup_with_token(env);
+    (client, admin)
+}
+
+fn setup_with_token(env: &Env) -> (GovernanceContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GovernanceContract);
+    let client = GovernanceContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract(Address::generate(env));
+    client.initialize(
+        &admin,
+        &token,
+        &Address::generate(env),
+        &1_000,
+        &Address::generate(env),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+    (client, admin, token)
+}
+
+fn voter_with_power(env: &Env, token: &Address, power: i128) -> Address {
+    let voter = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&voter, &power);
+    voter
+}
+
+#[test]
+fn test_upgrade_surface() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    assert_eq!(client.contract_version(), 1);
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    client.upgrade(&3);
+
+    let info = client.version_info();
+    assert_eq!(info.current_version, 3);
+    assert_eq!(info.previous_version, 1);
+    assert_eq!(client.version_history().len(), 1);
+}
+
+fn create_proposal(env: &Env, client: &GovernanceContractClient<'_>, threshold: u32) -> u64 {
+    client.create_proposal(
+        &Address::generate(env),
+        &String::from_str(env, "Raise premiums"),
+        &String::from_str(env, "Adjust base rates"),
+        &String::from_str(env, "noop"),
+        &threshold,
+    )
+}
+
+#[test]
+fn test_record_vote_outcome_adjusts_reputation() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let recorder = Address::generate(&env);
+    client.set_reputation_weights(&recorder, &2, &3);
+    let proposal_id = create_proposal(&env, &client, 50);
+
+    let aligned = Address::generate(&env);
+    let misaligned = Address::generate(&env);
+    client.vote(&aligned, &proposal_id, &70, &true);
+    client.vote(&misaligned, &proposal_id, &30, &false);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.finalize_proposal(&proposal_id);
+
+    assert_eq!(client.record_vote_outcome(&aligned, &proposal_id), 2);
+    assert_eq!(env.auths()[0].0, recorder);
+    assert_eq!(client.record_vote_outcome(&misaligned, &proposal_id), -3);
+    assert_eq!(client.get_voter_reputation(&aligned), 2);
+    assert_eq!(client.get_voter_reputation(&misaligned), -3);
+}
+
+#[test]
+#[should_panic(expected = "Reputation tracking not enabled")]
+fn test_record_vote_outcome_requires_tracking_enabled() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let proposal_id = create_proposal(&env, &client, 50);
+    let voter = Address::generate(&env);
+    client.vote(&voter, &proposal_id, &10, &true);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.finalize_proposal(&proposal_id);
+    client.record_vote_outcome(&voter, &proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Outcome already recorded")]
+fn test_record_vote_outcome_only_once() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_reputation_weights(&Address::generate(&env), &1, &1);
+    let proposal_id = create_proposal(&env, &client, 50);
+    let voter = Address::generate(&env);
+    client.vote(&voter, &proposal_id, &10, &true);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.finalize_proposal(&proposal_id);
+    client.record_vote_outcome(&voter, &proposal_id);
+    client.record_vote_outcome(&voter, &proposal_id);
+}
+
+#[test]
+fn test_quorum_met_proposal_passes() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_quorum(&100, &60);
+    let proposal_id = create_proposal(&env, &client, 51);
+    client.vote(&Address::generate(&env), &proposal_id, &60, &true);
+    client.vote(&Address::generate(&env), &proposal_id, &40, &false);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.finalize_proposal(&proposal_id);
+    assert_eq!(client.get_proposal_stats(&proposal_id).status, symbol_short!("finalized"));
+    client.execute_proposal(&proposal_id);
+}
+
+#[test]
+fn test_quorum_not_met_rejects_proposal() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_quorum(&100, &51);
+    let proposal_id = create_proposal(&env, &client, 51);
+    client.vote(&Address::generate(&env), &proposal_id, &99, &true);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.finalize_proposal(&proposal_id);
+    assert_eq!(client.get_proposal_stats(&proposal_id).status, symbol_short!("rejected"));
+}
+
+#[test]
+fn test_outcome_fixed_at_finalize_despite_quorum_change() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_quorum(&100, &51);
+    let passing = create_proposal(&env, &client, 51);
+    let failing = create_proposal(&env, &client, 51);
+    client.vote(&Address::generate(&env), &passing, &100, &true);
+    client.vote(&Address::generate(&env), &failing, &99, &true);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.finalize_proposal(&passing);
+    client.finalize_proposal(&failing);
+
+    client.set_quorum(&0, &51);
+    assert_eq!(client.get_proposal_stats(&failing).status, symbol_short!("rejected"));
+    assert!(client.try_execute_proposal(&failing).is_err());
+
+    client.set_quorum(&1_000, &51);
+    assert_eq!(client.get_proposal_stats(&passing).status, symbol_short!("finalized"));
+    client.execute_proposal(&passing);
+}
+
+#[test]
+#[should_panic(expected = "Already finalized")]
+fn test_finalize_only_once() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let proposal_id = create_proposal(&env, &client, 51);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.finalize_proposal(&proposal_id);
+    client.finalize_proposal(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Threshold not met")]
+fn test_quorum_threshold_overrides_lower_proposal_threshold() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_quorum(&0, &100);
+    let proposal_id = create_proposal(&env, &client, 51);
+    client.vote(&Address::generate(&env), &proposal_id, &99, &true);
+    client.vote(&Address::generate(&env), &proposal_id, &1, &false);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.finalize_proposal(&proposal_id);
+    client.execute_proposal(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Approval threshold must be between 51 and 100")]
+fn test_quorum_rejects_simple_plurality_threshold() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_quorum(&0, &50);
+}
+
+#[test]
+fn test_get_proposals_paginated() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    for _ in 0..3 {
+        create_proposal(&env, &client, 51);
+    }
+
+    let (page, total) = client.get_proposals_paginated(&0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, 1);
+    assert_eq!(total, 3);
+
+    let (page, _) = client.get_proposals_paginated(&2, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 3);
+
+    let (page, total) = client.get_proposals_paginated(&5, &10);
+    assert_eq!(page.len(), 0);
+    assert_eq!(total, 3);
+}
+
+#[test]
+fn test_change_vote_moves_weight_between_sides() {
+    let env = Env::default();
+    let (client, _admin, token) = setup_with_token(&env);
+    let proposal_id = create_proposal(&env, &client, 50);
+    let voter = voter_with_power(&env, &token, 100);
+    let other = Address::generate(&env);
+
+    client.vote(&voter, &proposal_id, &70, &true);
+    client.vote(&other, &proposal_id, &20, &true);
+    client.change_vote(&voter, &proposal_id, &40, &false);
+
+    let stats = client.get_proposal_stats(&proposal_id);
+    assert_eq!(stats.yes_votes, 20);
+    assert_eq!(stats.no_votes, 40);
+
+    let record = client.get_vote_record(&proposal_id, &voter).unwrap();
+    assert_eq!(record.weight, 40);
+    assert!(!record.is_yes);
+}
+
+#[test]
+#[should_panic(expected = "Voting period ended")]
+fn test_change_vote_rejected_after_voting_ends() {
+    let env = Env::default();
+    let (client, _admin, token) = setup_with_token(&env);
+    let proposal_id = create_proposal(&env, &client, 50);
+    let voter = voter_with_power(&env, &token, 100);
+    client.vote(&voter, &proposal_id, &70, &true);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.change_vote(&voter, &proposal_id, &70, &false);
+}
+
+#[test]
+#[should_panic(expected = "Vote weight must be positive")]
+fn test_change_vote_rejects_non_positive_weight() {
+    let env = Env::default();
+    let (client, _admin, token) = setup_with_token(&env);
+    let proposal_id = create_proposal(&env, &client, 50);
+    let voter = voter_with_power(&env, &token, 100);
+    client.vote(&voter, &proposal_id, &70, &true);
+
+    client.change_vote(&voter, &proposal_id, &-70, &false);
+}
+
+#[test]
+#[should_panic(expected = "Vote weight exceeds voting power")]
+fn test_change_vote_bounded_by_voting_power() {
+    let env = Env::default();
+    let (client, _admin, token) = setup_with_token(&env);
+    let proposal_id = create_proposal(&env, &client, 50);
+    let voter = voter_with_power(&env, &token, 100);
+    client.vote(&voter, &proposal_id, &70, &true);
+
+    client.change_vote(&voter, &proposal_id, &101, &true);
+}
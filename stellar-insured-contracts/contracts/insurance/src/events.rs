@@ -289,3 +289,11 @@ pub struct AdminChanged {
     pub new_admin: AccountId,
     pub timestamp: u64,
 }
+
+#[ink(event)]
+pub struct RoleExpired {
+    #[ink(topic)]
+    pub account: AccountId,
+    pub role: Role,
+    pub expired_at: u64,
+}
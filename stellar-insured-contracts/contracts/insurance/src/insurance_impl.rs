@@ -2161,6 +2161,57 @@
             Ok(())
         }
 
+        /// Grant `role` to `account` until `expires_at` (admin only). After that
+        /// the grant no longer satisfies role checks. Emits `RoleGranted`.
+        #[ink(message)]
+        pub fn grant_role_until(
+            &mut self,
+            account: AccountId,
+            role: Role,
+            expires_at: u64,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_role(Role::Admin)?;
+            if account == AccountId::from([0x0; 32]) {
+                panic!("Zero address not allowed");
+            }
+            if expires_at <= self.env().block_timestamp() {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.role_manager.grant_until(account, role, expires_at);
+            self.env().emit_event(RoleGranted {
+                account,
+                role,
+                granted_by: self.env().caller(),
+            });
+            Ok(())
+        }
+
+        /// Return the expiry of a time-bounded grant, or `None` if the grant is permanent.
+        #[ink(message)]
+        pub fn get_role_expiry(&self, account: AccountId, role: Role) -> Option<u64> {
+            self.role_manager.expiry_of(account, role)
+        }
+
+        /// Clear an expired grant and emit `RoleExpired`. Callable by anyone;
+        /// expired grants already fail role checks, this only tidies storage.
+        /// Role checks run in read-only messages, so unlike a lazily emitted
+        /// event, `RoleExpired` is not raised the first time an expired grant
+        /// is queried; it is raised here, once the grant is purged.
+        #[ink(message)]
+        pub fn purge_expired_role(&mut self, account: AccountId, role: Role) -> Result<(), InsuranceError> {
+            if !self.role_manager.is_expired(account, role) {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let expired_at = self.role_manager.expiry_of(account, role).unwrap_or_default();
+            self.role_manager.revoke(account, role);
+            self.env().emit_event(RoleExpired {
+                account,
+                role,
+                expired_at,
+            });
+            Ok(())
+        }
+
         /// Revoke `role` from `account` (admin only). Emits `RoleRevoked`. (#346)
         #[ink(message)]
         pub fn revoke_role(&mut self, account: AccountId, role: Role) -> Result<(), InsuranceError> {
@@ -2186,6 +2237,19 @@
             self.role_manager.has_role(account, role)
         }
 
+        /// Whether `account` would pass a `role` check, for clients deciding
+        /// which actions to offer. Unlike `has_role`, never panics.
+        #[ink(message)]
+        pub fn check_permission(&self, account: AccountId, role: Role) -> bool {
+            self.role_manager.has_role(account, role)
+        }
+
+        /// Whether `account` would pass a check for at least one of `roles`.
+        #[ink(message)]
+        pub fn check_any_permission(&self, account: AccountId, roles: Vec<Role>) -> bool {
+            roles.iter().any(|&role| self.role_manager.has_role(account, role))
+        }
+
         /// Return all roles held by `account`. (#346)
         #[ink(message)]
         pub fn get_roles(&self, account: AccountId) -> Vec<Role> {
@@ -2195,6 +2259,20 @@
             self.role_manager.roles_of(account)
         }
 
+        /// Return current role assignments in grant order, for audit tooling.
+        /// `start` skips that many assignments and `limit` is capped at 50.
+        #[ink(message)]
+        pub fn get_roles_paginated(&self, start: u32, limit: u32) -> Vec<(AccountId, Role)> {
+            self.role_manager.assignments_paginated(start, limit)
+        }
+
+        /// Return the `limit` most recent role grants and revocations, oldest
+        /// first. The log keeps the last 100 changes.
+        #[ink(message)]
+        pub fn get_role_change_history(&self, limit: u32) -> Vec<RoleChangeEntry> {
+            self.role_manager.history(limit)
+        }
+
         /// Authorize an oracle address (backwards-compatible wrapper)
         #[ink(message)]
         pub fn authorize_oracle(&mut self, oracle: AccountId) -> Result<(), InsuranceError> {
@@ -2350,7 +2428,7 @@
 
         /// Step 1 of 2: propose transferring admin rights to `new_admin`.
         /// The change takes effect only after `admin_timelock_delay` seconds
-        /// and a call to `execute_set_admin` (#301).
+        /// and a call to `execute_set_admin` or `accept_admin` (#301).
         #[ink(message)]
         pub fn propose_set_admin(&mut self, new_admin: AccountId) -> Result<(), InsuranceError> {
             self.ensure_role(Role::Admin)?;
@@ -2381,18 +2459,35 @@
             }
             let new_admin = self.pending_admin
                 .ok_or(InsuranceError::InvalidParameters)?;
-            let old_admin = self.admin;
-            self.admin = new_admin;
-            self.pending_admin = None;
-            self.pending_admin_after = None;
-            self.env().emit_event(AdminChanged {
-                old_admin,
-                new_admin,
-                timestamp: self.env().block_timestamp(),
-            });
+            self.apply_admin_transfer(new_admin);
             Ok(())
         }
 
+        /// Step 2 of 2, driven by the proposed admin: the pending address
+        /// accepts the transfer once the time-lock delay has elapsed. The old
+        /// admin loses the `Admin` role only here, on acceptance.
+        #[ink(message)]
+        pub fn accept_admin(&mut self) -> Result<(), InsuranceError> {
+            let new_admin = self.pending_admin
+                .ok_or(InsuranceError::InvalidParameters)?;
+            if self.env().caller() != new_admin {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let earliest = self.pending_admin_after
+                .ok_or(InsuranceError::InvalidParameters)?;
+            if self.env().block_timestamp() < earliest {
+                return Err(InsuranceError::TimeLockNotReady);
+            }
+            self.apply_admin_transfer(new_admin);
+            Ok(())
+        }
+
+        /// Return the address proposed as the next admin, if any.
+        #[ink(message)]
+        pub fn get_pending_admin(&self) -> Option<AccountId> {
+            self.pending_admin
+        }
+
         /// Cancel a pending admin proposal (admin only) (#301).
         #[ink(message)]
         pub fn cancel_pending_admin(&mut self) -> Result<(), InsuranceError> {
@@ -2715,6 +2810,22 @@
                 pool.accumulated_reward_per_share.saturating_add(inc);
         }
 
+        /// Swap the stored admin and the `Admin` role assignment, clearing
+        /// the pending proposal.
+        fn apply_admin_transfer(&mut self, new_admin: AccountId) {
+            let old_admin = self.admin;
+            self.admin = new_admin;
+            self.role_manager.revoke(old_admin, Role::Admin);
+            self.role_manager.grant(new_admin, Role::Admin);
+            self.pending_admin = None;
+            self.pending_admin_after = None;
+            self.env().emit_event(AdminChanged {
+                old_admin,
+                new_admin,
+                timestamp: self.env().block_timestamp(),
+            });
+        }
+
         /// Check that the caller holds `role` (or Admin, which satisfies every role).
         fn ensure_role(&self, role: Role) -> Result<(), InsuranceError> {
             if !self.role_manager.has_role(self.env().caller(), role) {
@@ -2308,6 +2308,224 @@
         assert!(!roles.contains(&crate::Role::Admin));
     }
 
+    #[ink::test]
+    fn test_get_roles_paginated_skips_revoked_assignments() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract
+            .grant_role(accounts.bob, crate::Role::Assessor)
+            .unwrap();
+        contract
+            .grant_role(accounts.charlie, crate::Role::Oracle)
+            .unwrap();
+        contract
+            .grant_role(accounts.django, crate::Role::Underwriter)
+            .unwrap();
+        contract
+            .revoke_role(accounts.charlie, crate::Role::Oracle)
+            .unwrap();
+
+        let all = contract.get_roles_paginated(0, 50);
+        // alice keeps the Admin role granted at construction
+        assert_eq!(all.len(), 3);
+        assert!(all.contains(&(accounts.alice, crate::Role::Admin)));
+        assert!(all.contains(&(accounts.bob, crate::Role::Assessor)));
+        assert!(all.contains(&(accounts.django, crate::Role::Underwriter)));
+        assert!(!all.iter().any(|(account, _)| *account == accounts.charlie));
+
+        let page = contract.get_roles_paginated(1, 1);
+        assert_eq!(page, vec![(accounts.bob, crate::Role::Assessor)]);
+    }
+
+    #[ink::test]
+    fn test_get_roles_paginated_caps_assignments_per_page() {
+        let mut contract = setup();
+        for i in 0..20u8 {
+            let account = ink::primitives::AccountId::from([0x10 + i; 32]);
+            contract.grant_role(account, crate::Role::Assessor).unwrap();
+            contract.grant_role(account, crate::Role::Oracle).unwrap();
+            contract.grant_role(account, crate::Role::Underwriter).unwrap();
+        }
+
+        // alice's Admin grant plus 60 new assignments
+        let first = contract.get_roles_paginated(0, 100);
+        assert_eq!(first.len(), 50);
+        let rest = contract.get_roles_paginated(50, 100);
+        assert_eq!(rest.len(), 11);
+        assert_eq!(rest[10], (ink::primitives::AccountId::from([0x23; 32]), crate::Role::Underwriter));
+    }
+
+    #[ink::test]
+    fn test_grant_role_until_expires() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract
+            .grant_role_until(accounts.bob, crate::Role::Assessor, 3_500_000)
+            .unwrap();
+        assert!(contract.has_role(accounts.bob, crate::Role::Assessor));
+        assert_eq!(
+            contract.get_role_expiry(accounts.bob, crate::Role::Assessor),
+            Some(3_500_000)
+        );
+        assert_eq!(
+            contract.purge_expired_role(accounts.bob, crate::Role::Assessor),
+            Err(InsuranceError::InvalidParameters)
+        );
+
+        test::set_block_timestamp::<DefaultEnvironment>(3_500_000);
+        assert!(!contract.has_role(accounts.bob, crate::Role::Assessor));
+        assert!(contract.get_roles(accounts.bob).is_empty());
+
+        contract
+            .purge_expired_role(accounts.bob, crate::Role::Assessor)
+            .unwrap();
+        assert_eq!(contract.get_role_expiry(accounts.bob, crate::Role::Assessor), None);
+    }
+
+    #[ink::test]
+    fn test_regrant_after_expiry_recorded_in_history() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract
+            .grant_role_until(accounts.bob, crate::Role::Assessor, 3_500_000)
+            .unwrap();
+        // Extending a live grant is not a new grant
+        contract
+            .grant_role_until(accounts.bob, crate::Role::Assessor, 3_600_000)
+            .unwrap();
+        assert_eq!(contract.get_role_change_history(10).len(), 2);
+
+        test::set_block_timestamp::<DefaultEnvironment>(3_600_000);
+        contract
+            .grant_role_until(accounts.bob, crate::Role::Assessor, 4_000_000)
+            .unwrap();
+
+        let history = contract.get_role_change_history(10);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[2].target, accounts.bob);
+        assert_eq!(history[2].new_role, Some(crate::Role::Assessor));
+        assert!(contract.has_role(accounts.bob, crate::Role::Assessor));
+    }
+
+    #[ink::test]
+    fn test_grant_role_until_rejects_past_expiry() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(
+            contract.grant_role_until(accounts.bob, crate::Role::Assessor, 3_000_000),
+            Err(InsuranceError::InvalidParameters)
+        );
+        // Permanent grants are unaffected
+        contract
+            .grant_role(accounts.bob, crate::Role::Assessor)
+            .unwrap();
+        assert_eq!(contract.get_role_expiry(accounts.bob, crate::Role::Assessor), None);
+    }
+
+    #[ink::test]
+    fn test_role_change_history_records_grant_then_revoke() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.grant_role(accounts.bob, crate::Role::Assessor).unwrap();
+        contract.revoke_role(accounts.bob, crate::Role::Assessor).unwrap();
+
+        let history = contract.get_role_change_history(2);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].actor, accounts.alice);
+        assert_eq!(history[0].target, accounts.bob);
+        assert_eq!(history[0].old_role, None);
+        assert_eq!(history[0].new_role, Some(crate::Role::Assessor));
+        assert_eq!(history[1].old_role, Some(crate::Role::Assessor));
+        assert_eq!(history[1].new_role, None);
+
+        // The constructor's admin grant precedes both
+        assert_eq!(contract.get_role_change_history(10).len(), 3);
+    }
+
+    #[ink::test]
+    fn test_role_change_history_keeps_latest_entries() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        for _ in 0..60 {
+            contract.grant_role(accounts.bob, crate::Role::Assessor).unwrap();
+            contract.revoke_role(accounts.bob, crate::Role::Assessor).unwrap();
+        }
+
+        // 121 changes were recorded; only the last 100 are retained
+        let history = contract.get_role_change_history(200);
+        assert_eq!(history.len(), crate::rbac::MAX_ROLE_HISTORY as usize);
+        assert_eq!(history[0].new_role, Some(crate::Role::Assessor));
+        assert_eq!(history[99].old_role, Some(crate::Role::Assessor));
+        assert_eq!(history[99].new_role, None);
+    }
+
+    #[ink::test]
+    fn test_check_permission_matches_role_enforcement() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.grant_role(accounts.bob, crate::Role::Assessor).unwrap();
+
+        assert!(contract.check_permission(accounts.bob, crate::Role::Assessor));
+        assert!(!contract.check_permission(accounts.bob, crate::Role::Admin));
+        assert!(contract.check_permission(accounts.alice, crate::Role::Oracle));
+        assert!(contract.check_any_permission(
+            accounts.bob,
+            vec![crate::Role::Oracle, crate::Role::Assessor]
+        ));
+        assert!(!contract.check_any_permission(accounts.charlie, vec![crate::Role::Oracle]));
+        assert!(!contract.check_permission(ink::primitives::AccountId::from([0x0; 32]), crate::Role::Admin));
+
+        // Agrees with enforcement: bob is not admin, so an admin call fails
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.grant_role(accounts.charlie, crate::Role::Oracle),
+            Err(InsuranceError::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_accept_admin_swaps_admin_role() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.propose_set_admin(accounts.bob).unwrap();
+        assert_eq!(contract.get_pending_admin(), Some(accounts.bob));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.accept_admin(), Err(InsuranceError::TimeLockNotReady));
+
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000 + 86_400);
+        contract.accept_admin().unwrap();
+        assert_eq!(contract.get_admin(), accounts.bob);
+        assert!(contract.has_role(accounts.bob, crate::Role::Admin));
+        assert!(!contract.has_role(accounts.alice, crate::Role::Admin));
+        assert_eq!(contract.get_pending_admin(), None);
+    }
+
+    #[ink::test]
+    fn test_accept_admin_rejects_wrong_caller() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.propose_set_admin(accounts.bob).unwrap();
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000 + 86_400);
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.accept_admin(), Err(InsuranceError::Unauthorized));
+        assert!(contract.has_role(accounts.alice, crate::Role::Admin));
+    }
+
+    #[ink::test]
+    fn test_cancel_pending_admin_blocks_acceptance() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.propose_set_admin(accounts.bob).unwrap();
+        contract.cancel_pending_admin().unwrap();
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000 + 86_400);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.accept_admin(), Err(InsuranceError::InvalidParameters));
+        assert_eq!(contract.get_admin(), accounts.alice);
+    }
+
     #[ink::test]
     fn test_authorize_oracle_backwards_compat() {
         let mut contract = setup();
@@ -11,14 +11,14 @@
 use ink::storage::Mapping;
 
 mod rbac;
-pub use rbac::{Role, RoleManager};
+pub use rbac::{Role, RoleChangeEntry, RoleManager};
 
 /// Decentralized Property Insurance Platform
 #[ink::contract]
 mod propchain_insurance {
     use super::*;
     use ink::prelude::{string::String, vec::Vec};
-    use crate::{Role, RoleManager};
+    use crate::{Role, RoleChangeEntry, RoleManager};
 
     pub use crate::types::{
         ActuarialModel, BatchClaimResult, BatchClaimSummary, ClaimStatus, CoverageType,
@@ -6,6 +6,20 @@
 use ink::prelude::vec::Vec;
 use ink::storage::Mapping;
 
+/// Maximum assignments returned by one page of [`RoleManager::assignments_paginated`].
+pub const MAX_ROLES_PAGE: u32 = 50;
+
+/// Role changes retained in the audit log; the oldest entry is overwritten first.
+pub const MAX_ROLE_HISTORY: u64 = 100;
+
+const ALL_ROLES: [Role; 5] = [
+    Role::Admin,
+    Role::Assessor,
+    Role::Oracle,
+    Role::Underwriter,
+    Role::Policyholder,
+];
+
 /// All roles recognised by the insurance contract.
 ///
 /// Roles are additive: a caller may hold multiple roles simultaneously.
@@ -34,6 +48,18 @@ pub enum Role {
     Policyholder,
 }
 
+/// One grant or revocation in the role audit log. A grant has no
+/// `old_role`; a revocation has no `new_role`.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct RoleChangeEntry {
+    pub actor: ink::primitives::AccountId,
+    pub target: ink::primitives::AccountId,
+    pub old_role: Option<Role>,
+    pub new_role: Option<Role>,
+    pub timestamp: u64,
+}
+
 /// Compact on-chain role store.
 ///
 /// Stored as a flat `Mapping<(AccountId, u8), bool>` where the `u8` is the
@@ -43,49 +69,153 @@ pub enum Role {
 pub struct RoleManager {
     /// `(account, role_discriminant) -> has_role`
     roles: Mapping<(ink::primitives::AccountId, u8), bool>,
+    /// `index -> account` for every account that has ever been granted a
+    /// role, in grant order. Never shrinks; revoked accounts are filtered
+    /// out when reading.
+    members: Mapping<u32, ink::primitives::AccountId>,
+    /// `account -> is_indexed` guard so each account is indexed once.
+    is_member: Mapping<ink::primitives::AccountId, bool>,
+    /// Number of indexed accounts.
+    member_count: u32,
+    /// `(account, role_discriminant) -> expires_at` for time-bounded grants.
+    expiries: Mapping<(ink::primitives::AccountId, u8), u64>,
+    /// Ring buffer of the last [`MAX_ROLE_HISTORY`] role changes, keyed by
+    /// `sequence % MAX_ROLE_HISTORY`.
+    history: Mapping<u64, RoleChangeEntry>,
+    /// Number of role changes ever recorded.
+    history_count: u64,
 }
 
 impl RoleManager {
     /// Assign `role` to `account`.
     pub fn grant(&mut self, account: ink::primitives::AccountId, role: Role) {
+        let held = self.holds(account, role);
+        self.expiries.remove(&(account, role as u8));
+        self.insert(account, role, held);
+    }
+
+    /// Assign `role` to `account` until the block timestamp reaches `expires_at`.
+    pub fn grant_until(&mut self, account: ink::primitives::AccountId, role: Role, expires_at: u64) {
+        let held = self.holds(account, role);
+        self.insert(account, role, held);
+        self.expiries.insert(&(account, role as u8), &expires_at);
+    }
+
+    /// Store the grant, logging it unless `account` already `held` the role.
+    /// Re-granting a role whose previous grant expired is logged as a grant.
+    fn insert(&mut self, account: ink::primitives::AccountId, role: Role, held: bool) {
+        if !held {
+            self.record(account, None, Some(role));
+        }
         self.roles.insert(&(account, role as u8), &true);
+        if !self.is_member.get(&account).unwrap_or(false) {
+            self.is_member.insert(&account, &true);
+            self.members.insert(&self.member_count, &account);
+            self.member_count += 1;
+        }
     }
 
     /// Remove `role` from `account`.
     pub fn revoke(&mut self, account: ink::primitives::AccountId, role: Role) {
+        if self.roles.get(&(account, role as u8)).unwrap_or(false) {
+            self.record(account, Some(role), None);
+        }
         self.roles.remove(&(account, role as u8));
+        self.expiries.remove(&(account, role as u8));
+    }
+
+    fn record(
+        &mut self,
+        target: ink::primitives::AccountId,
+        old_role: Option<Role>,
+        new_role: Option<Role>,
+    ) {
+        let entry = RoleChangeEntry {
+            actor: ink::env::caller::<ink::env::DefaultEnvironment>(),
+            target,
+            old_role,
+            new_role,
+            timestamp: ink::env::block_timestamp::<ink::env::DefaultEnvironment>(),
+        };
+        self.history
+            .insert(&(self.history_count % MAX_ROLE_HISTORY), &entry);
+        self.history_count += 1;
+    }
+
+    /// Return the `limit` most recent role changes, oldest first.
+    pub fn history(&self, limit: u32) -> Vec<RoleChangeEntry> {
+        let retained = self.history_count.min(MAX_ROLE_HISTORY);
+        let start = self.history_count - (limit as u64).min(retained);
+        (start..self.history_count)
+            .filter_map(|seq| self.history.get(&(seq % MAX_ROLE_HISTORY)))
+            .collect()
+    }
+
+    /// Expiry of a time-bounded grant, or `None` for permanent grants.
+    pub fn expiry_of(&self, account: ink::primitives::AccountId, role: Role) -> Option<u64> {
+        self.expiries.get(&(account, role as u8))
+    }
+
+    /// Return `true` if `account` was granted `role` and the grant has expired.
+    pub fn is_expired(&self, account: ink::primitives::AccountId, role: Role) -> bool {
+        self.roles.get(&(account, role as u8)).unwrap_or(false) && !self.holds(account, role)
+    }
+
+    /// Return `true` if `account` directly holds an unexpired `role`.
+    fn holds(&self, account: ink::primitives::AccountId, role: Role) -> bool {
+        if !self.roles.get(&(account, role as u8)).unwrap_or(false) {
+            return false;
+        }
+        match self.expiry_of(account, role) {
+            Some(expires_at) => {
+                ink::env::block_timestamp::<ink::env::DefaultEnvironment>() < expires_at
+            }
+            None => true,
+        }
     }
 
     /// Return `true` if `account` holds `role` **or** the `Admin` role.
     pub fn has_role(&self, account: ink::primitives::AccountId, role: Role) -> bool {
         // Admin satisfies every role check.
-        if role != Role::Admin
-            && self
-                .roles
-                .get(&(account, Role::Admin as u8))
-                .unwrap_or(false)
-        {
+        if role != Role::Admin && self.holds(account, Role::Admin) {
             return true;
         }
-        self.roles.get(&(account, role as u8)).unwrap_or(false)
+        self.holds(account, role)
     }
 
     /// Return all roles currently held by `account`.
     pub fn roles_of(&self, account: ink::primitives::AccountId) -> Vec<Role> {
-        let all = [
-            Role::Admin,
-            Role::Assessor,
-            Role::Oracle,
-            Role::Underwriter,
-            Role::Policyholder,
-        ];
-        all.iter()
-            .filter(|&&r| {
-                self.roles
-                    .get(&(account, r as u8))
-                    .unwrap_or(false)
-            })
+        ALL_ROLES
+            .iter()
+            .filter(|&&r| self.holds(account, r))
             .copied()
             .collect()
     }
+
+    /// Return at most `limit` current `(account, role)` assignments (capped at
+    /// [`MAX_ROLES_PAGE`]), skipping the first `start` in grant order.
+    pub fn assignments_paginated(
+        &self,
+        start: u32,
+        limit: u32,
+    ) -> Vec<(ink::primitives::AccountId, Role)> {
+        let limit = limit.min(MAX_ROLES_PAGE) as usize;
+        let mut out = Vec::new();
+        let mut skipped = 0u32;
+        for index in 0..self.member_count {
+            if out.len() >= limit {
+                break;
+            }
+            if let Some(account) = self.members.get(&index) {
+                for role in self.roles_of(account) {
+                    if skipped < start {
+                        skipped += 1;
+                    } else if out.len() < limit {
+                        out.push((account, role));
+                    }
+                }
+            }
+        }
+        out
+    }
 }
@@ -4,6 +4,9 @@
 
 pub mod random;
 pub mod insurance_types;
+pub mod upgradeable;
+pub mod oracle;
 
 pub use random::Randomness;
 pub use insurance_types::*;
+pub use upgradeable::Upgradeable;
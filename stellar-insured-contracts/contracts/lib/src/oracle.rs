@@ -0,0 +1,102 @@
+//! Validated price reads for parametric policies.
+//!
+//! Contracts store an [`OracleConfig`] with [`set_oracle_config`] and read
+//! prices through [`get_validated_price`], which takes the median of the
+//! oracle's recent submissions for a feed and rejects stale, sparse or
+//! outlying data.
+
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, Vec};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum OracleKey {
+    Config,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleConfig {
+    /// Contract exposing `submissions(feed: Symbol) -> Vec<PriceSubmission>`.
+    pub oracle: Address,
+    /// Submissions older than this many seconds are ignored.
+    pub max_staleness: u64,
+    /// Fresh submissions required before a price is trusted.
+    pub min_submissions: u32,
+    /// Largest allowed distance from the median, in basis points.
+    pub max_deviation_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceSubmission {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+pub fn set_oracle_config(env: &Env, config: &OracleConfig) {
+    if config.min_submissions == 0 || config.max_staleness == 0 {
+        panic!("Invalid oracle config");
+    }
+    env.storage().instance().set(&OracleKey::Config, config);
+}
+
+pub fn oracle_config(env: &Env) -> OracleConfig {
+    env.storage()
+        .instance()
+        .get(&OracleKey::Config)
+        .unwrap_or_else(|| panic!("Oracle not configured"))
+}
+
+/// Returns the median of the fresh submissions for `feed`.
+///
+/// Panics with "Oracle data stale" when only stale submissions are left,
+/// "Insufficient oracle submissions" when fewer than `min_submissions` are
+/// fresh, and "Oracle outlier detected" when any fresh submission is further
+/// than `max_deviation_bps` from the median.
+pub fn get_validated_price(env: &Env, feed: Symbol) -> i128 {
+    let config = oracle_config(env);
+    let submissions: Vec<PriceSubmission> = env.invoke_contract(
+        &config.oracle,
+        &Symbol::new(env, "submissions"),
+        (feed,).into_val(env),
+    );
+
+    let now = env.ledger().timestamp();
+    let mut prices: Vec<i128> = Vec::new(env);
+    for submission in submissions.iter() {
+        if now.saturating_sub(submission.timestamp) <= config.max_staleness {
+            insert_sorted(&mut prices, submission.price);
+        }
+    }
+
+    if prices.len() < config.min_submissions {
+        if prices.len() < submissions.len() {
+            panic!("Oracle data stale");
+        }
+        panic!("Insufficient oracle submissions");
+    }
+
+    let mid = prices.len() / 2;
+    let median = if prices.len() % 2 == 0 {
+        (prices.get_unchecked(mid - 1) + prices.get_unchecked(mid)) / 2
+    } else {
+        prices.get_unchecked(mid)
+    };
+
+    let tolerance = median.abs() * config.max_deviation_bps as i128 / 10_000;
+    for price in prices.iter() {
+        if (price - median).abs() > tolerance {
+            panic!("Oracle outlier detected");
+        }
+    }
+
+    median
+}
+
+fn insert_sorted(prices: &mut Vec<i128>, price: i128) {
+    let mut index = prices.len();
+    while index > 0 && prices.get_unchecked(index - 1) > price {
+        index -= 1;
+    }
+    prices.insert(index, price);
+}
@@ -0,0 +1,152 @@
+//! Uniform upgrade surface shared by protocol contracts.
+//!
+//! Contracts adopt it by calling [`init_version`] from `initialize` and
+//! implementing [`Upgradeable`] in a `#[contractimpl]` block that delegates to
+//! the helpers below, so tooling can drive every contract the same way.
+
+use soroban_sdk::{contracttype, symbol_short, Env, Vec};
+
+/// Maximum version transitions retained in history (prevents unbounded growth).
+pub const MAX_VERSION_HISTORY: u32 = 20;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum UpgradeKey {
+    ContractVersion,
+    VersionHistory,
+    UpgradedAt,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionTransition {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub timestamp: u64,
+    /// Set when the transition was a rollback rather than an upgrade.
+    pub is_rollback: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionInfo {
+    pub current_version: u32,
+    /// Version before the most recent upgrade, or `0` if never upgraded.
+    pub previous_version: u32,
+    pub last_upgraded_at: u64,
+}
+
+/// Entry points every upgradeable contract exposes.
+///
+/// Authorization is left to the implementor: `upgrade` and `rollback` must
+/// check the contract's own admin before calling [`upgrade`] or [`rollback`].
+pub trait Upgradeable {
+    fn contract_version(env: Env) -> u32;
+    fn upgrade(env: Env, new_version: u32);
+    fn rollback(env: Env, target_version: u32);
+    fn version_info(env: Env) -> VersionInfo;
+    fn version_history(env: Env) -> Vec<VersionTransition>;
+}
+
+/// Records the starting version. Call once from `initialize`.
+pub fn init_version(env: &Env, version: u32) {
+    if env.storage().instance().has(&UpgradeKey::ContractVersion) {
+        panic!("Version already initialized");
+    }
+    env.storage().instance().set(&UpgradeKey::ContractVersion, &version);
+    env.storage().instance().set(&UpgradeKey::UpgradedAt, &env.ledger().timestamp());
+}
+
+pub fn contract_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&UpgradeKey::ContractVersion)
+        .unwrap_or_else(|| panic!("Contract not initialized"))
+}
+
+pub fn version_history(env: &Env) -> Vec<VersionTransition> {
+    env.storage()
+        .instance()
+        .get(&UpgradeKey::VersionHistory)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn version_info(env: &Env) -> VersionInfo {
+    let previous_version = match version_history(env).last() {
+        Some(transition) => transition.from_version,
+        None => 0,
+    };
+    VersionInfo {
+        current_version: contract_version(env),
+        previous_version,
+        last_upgraded_at: env.storage().instance().get(&UpgradeKey::UpgradedAt).unwrap_or(0),
+    }
+}
+
+/// Whether the contract is at `min_version` or later. Lets a function turn on
+/// behaviour added in a later version without a dedicated migration.
+pub fn feature_enabled(env: &Env, min_version: u32) -> bool {
+    contract_version(env) >= min_version
+}
+
+/// Panics unless the contract is at `min_version` or later.
+pub fn require_feature(env: &Env, min_version: u32) {
+    if !feature_enabled(env, min_version) {
+        panic!("Feature requires a newer contract version");
+    }
+}
+
+/// Moves the contract to `new_version`, which must be strictly greater than
+/// the current one, and appends the transition to the history.
+pub fn upgrade(env: &Env, new_version: u32) {
+    let current = contract_version(env);
+    if new_version <= current {
+        panic!("New version must be greater than current version");
+    }
+    record_transition(env, current, new_version, false);
+
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("upgrade")),
+        (current, new_version),
+    );
+}
+
+/// Moves the contract back to `target_version` after a bad upgrade. The
+/// target must be lower than the current version and one the contract has
+/// run before; the rollback is appended to the history like an upgrade.
+pub fn rollback(env: &Env, target_version: u32) {
+    let current = contract_version(env);
+    if target_version >= current {
+        panic!("Rollback target must be lower than current version");
+    }
+    let known = version_history(env)
+        .iter()
+        .any(|t| t.from_version == target_version || t.to_version == target_version);
+    if !known {
+        panic!("Rollback target not in version history");
+    }
+    record_transition(env, current, target_version, true);
+
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("rollback")),
+        (current, target_version),
+    );
+}
+
+fn record_transition(env: &Env, from_version: u32, to_version: u32, is_rollback: bool) {
+    let now = env.ledger().timestamp();
+    let mut history = version_history(env);
+    if history.len() >= MAX_VERSION_HISTORY {
+        history.remove(0);
+    }
+    history.push_back(VersionTransition {
+        from_version,
+        to_version,
+        timestamp: now,
+        is_rollback,
+    });
+
+    env.storage().instance().set(&UpgradeKey::ContractVersion, &to_version);
+    env.storage().instance().set(&UpgradeKey::VersionHistory, &history);
+    env.storage().instance().set(&UpgradeKey::UpgradedAt, &now);
+}
@@ -1,7 +1,22 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env};
-use stellar_insured_lib::{InsurancePolicy, PolicyStatus, PolicyType};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, token, Address, Env, IntoVal, Symbol, Vec,
+};
+use stellar_insured_lib::oracle::{self, OracleConfig};
+use stellar_insured_lib::upgradeable::{self, VersionInfo, VersionTransition};
+use stellar_insured_lib::{InsurancePolicy, PolicyStatus, PolicyType, Upgradeable};
+
+const CONTRACT_VERSION: u32 = 1;
+// Maximum endorsements recorded per policy to prevent storage bloat
+const MAX_ENDORSEMENTS: u32 = 20;
+// Maximum perils bundled into a single policy
+const MAX_PERILS: u32 = 10;
+// Maximum risk pools sharing a co-insured policy
+const MAX_COINSURERS: u32 = 5;
+// Maximum claim holds kept in a policy's audit trail
+const MAX_HOLD_HISTORY: u32 = 20;
+const MAX_PAGINATION_LIMIT: u32 = 50;
 
 #[contracttype]
 #[derive(Clone)]
@@ -11,6 +26,95 @@ pub enum DataKey {
     ClaimsContract,
     Policy(u64),
     PolicyCounter,
+    PremiumToken,
+    CoverageDenom,
+    WaitingPeriod,
+    PremiumEscrow(u64),
+    CoverageBounds(PolicyType),
+    Endorsements(u64),
+    NoClaimsBonus,
+    ClaimCount(u64),
+    ClaimFreeTerms(u64),
+    TermHadClaim(u64),
+    BasePremium(u64),
+    /// Length in days of the term a renewal started; absent before the first renewal.
+    TermDays(u64),
+    Peril(u64, Symbol),
+    PremiumRebateBps,
+    PendingRebate(Address),
+    TermRebate(u64),
+    ManagerCapacity(Address),
+    ManagerCapacityUsed(Address),
+    PolicyManager(u64),
+    Coinsurance(u64),
+    ClaimProcessor(Address),
+    ClaimHold(u64),
+    ClaimHoldHistory(u64),
+    PremiumLender(u64),
+    /// Ids of every policy issued to a holder, in issue order.
+    HolderPolicies(Address),
+    CollectedPremiums,
+    PremiumsCharged(u64),
+    /// Premium actually received for a policy across its terms, net of refunds.
+    PremiumCollected(u64),
+    ExperienceRating,
+    ZoneLimit(Symbol),
+    ZoneExposure(Symbol),
+    PolicyZone(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EscrowStatus {
+    Held,
+    Released,
+    Refunded,
+}
+
+/// Premium held by the contract until coverage starts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PremiumEscrow {
+    pub amount: i128,
+    pub coverage_starts_at: u64,
+    pub status: EscrowStatus,
+}
+
+/// Mid-term coverage addition. Coverage reductions are recorded with
+/// negative amounts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Endorsement {
+    pub additional_coverage: i128,
+    pub premium_charged: i128,
+    pub timestamp: u64,
+}
+
+/// Sub-limit for one peril of a multi-peril policy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PerilCoverage {
+    pub limit: i128,
+    pub claimed: i128,
+}
+
+/// One risk pool's share of a co-insured policy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoinsuranceShare {
+    pub pool: Address,
+    pub share_bps: u32,
+    pub claimed: i128,
+}
+
+/// Temporary freeze on a policy's claims pending a fraud investigation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimHold {
+    pub processor: Address,
+    pub reason: Symbol,
+    pub placed_at: u64,
+    pub hold_until: u64,
 }
 
 // --- Storage helpers (#378: data access abstraction) ---
@@ -31,6 +135,475 @@ fn set_policy(env: &Env, policy_id: u64, policy: &InsurancePolicy) {
     env.storage().persistent().set(&DataKey::Policy(policy_id), policy);
 }
 
+fn get_holder_policies(env: &Env, holder: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderPolicies(holder.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn require_coverage_within_bounds(env: &Env, policy_type: &PolicyType, coverage_amount: i128) {
+    if let Some((min, max)) = env
+        .storage()
+        .instance()
+        .get::<DataKey, (i128, i128)>(&DataKey::CoverageBounds(policy_type.clone()))
+    {
+        if coverage_amount < min || coverage_amount > max {
+            panic!("Coverage amount outside bounds for policy type");
+        }
+    }
+}
+
+/// Seconds left in the policy's current term at `now`, and the term's length.
+/// A renewal starts a new term of the renewed duration, so mid-term pricing
+/// ignores the days accumulated by earlier terms.
+fn current_term_remaining(env: &Env, policy: &InsurancePolicy, now: u64) -> (u64, u64) {
+    let term_days: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::TermDays(policy.policy_id))
+        .unwrap_or(policy.duration_days);
+    let term = term_days as u64 * 86400;
+    let expiry = policy.start_time + (policy.duration_days as u64 * 86400);
+    if now >= expiry {
+        panic!("Policy has expired");
+    }
+    ((expiry - now).min(term), term)
+}
+
+fn get_claim_free_terms(env: &Env, policy_id: u64) -> u32 {
+    env.storage().persistent().get(&DataKey::ClaimFreeTerms(policy_id)).unwrap_or(0)
+}
+
+/// Discount in basis points earned by `claim_free_terms` consecutive claim-free terms.
+fn no_claims_discount_bps(env: &Env, claim_free_terms: u32) -> u32 {
+    match env.storage().instance().get::<DataKey, (u32, u32)>(&DataKey::NoClaimsBonus) {
+        Some((per_term_bps, max_bps)) => (claim_free_terms.saturating_mul(per_term_bps)).min(max_bps),
+        None => 0,
+    }
+}
+
+fn add_premiums_charged(env: &Env, policy_id: u64, amount: i128) {
+    let charged: i128 = env.storage().persistent().get(&DataKey::PremiumsCharged(policy_id)).unwrap_or(0);
+    env.storage().persistent().set(&DataKey::PremiumsCharged(policy_id), &(charged + amount));
+}
+
+fn get_premium_collected(env: &Env, policy_id: u64) -> i128 {
+    env.storage().persistent().get(&DataKey::PremiumCollected(policy_id)).unwrap_or(0)
+}
+
+fn add_premium_collected(env: &Env, policy_id: u64, amount: i128) {
+    let collected = get_premium_collected(env, policy_id);
+    env.storage().persistent().set(&DataKey::PremiumCollected(policy_id), &(collected + amount));
+}
+
+/// Claims paid over premiums charged across all of the policy's terms, in basis points.
+fn loss_ratio_bps(env: &Env, policy: &InsurancePolicy) -> u32 {
+    let charged: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PremiumsCharged(policy.policy_id))
+        .unwrap_or(0);
+    if charged <= 0 {
+        return 0;
+    }
+    (policy.total_claimed * 10_000 / charged).min(u32::MAX as i128) as u32
+}
+
+/// Premium multiplier, in basis points, from the policy's loss ratio relative
+/// to the configured target, clamped to the configured bounds.
+fn experience_factor_bps(env: &Env, policy: &InsurancePolicy) -> u32 {
+    match env.storage().instance().get::<DataKey, (u32, u32, u32)>(&DataKey::ExperienceRating) {
+        Some((target_bps, min_factor_bps, max_factor_bps)) => {
+            let factor = loss_ratio_bps(env, policy) as u64 * 10_000 / target_bps as u64;
+            (factor.min(u32::MAX as u64) as u32).clamp(min_factor_bps, max_factor_bps)
+        }
+        None => 10_000,
+    }
+}
+
+fn get_coverage_denom(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::CoverageDenom)
+        .unwrap_or_else(|| panic!("Contract not initialized"))
+}
+
+fn get_escrow(env: &Env, policy_id: u64) -> Option<PremiumEscrow> {
+    env.storage().persistent().get(&DataKey::PremiumEscrow(policy_id))
+}
+
+fn set_escrow(env: &Env, policy_id: u64, escrow: &PremiumEscrow) {
+    env.storage().persistent().set(&DataKey::PremiumEscrow(policy_id), escrow);
+}
+
+/// Accrues the configured share of `premium` to the holder's rebate balance
+/// for the policy's current term.
+fn accrue_rebate(env: &Env, policy_id: u64, holder: &Address, premium: i128) {
+    let rebate_bps: u32 = env.storage().instance().get(&DataKey::PremiumRebateBps).unwrap_or(0);
+    let amount = premium * rebate_bps as i128 / 10_000;
+    if amount <= 0 {
+        return;
+    }
+    env.storage().persistent().set(&DataKey::TermRebate(policy_id), &amount);
+    let key = DataKey::PendingRebate(holder.clone());
+    let pending: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(pending + amount));
+}
+
+/// Removes the rebate accrued in the policy's current term from the holder's
+/// balance and returns it.
+fn take_term_rebate(env: &Env, policy_id: u64, holder: &Address) -> i128 {
+    let amount: i128 = env.storage().persistent().get(&DataKey::TermRebate(policy_id)).unwrap_or(0);
+    if amount == 0 {
+        return 0;
+    }
+    env.storage().persistent().remove(&DataKey::TermRebate(policy_id));
+    let key = DataKey::PendingRebate(holder.clone());
+    let pending: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(pending - amount));
+    amount
+}
+
+fn get_coinsurance(env: &Env, policy_id: u64) -> Option<Vec<CoinsuranceShare>> {
+    env.storage().persistent().get(&DataKey::Coinsurance(policy_id))
+}
+
+/// Splits `amount` across co-insurers by share; the last pool takes the rounding remainder.
+fn split_by_shares(env: &Env, shares: &Vec<CoinsuranceShare>, amount: i128) -> Vec<i128> {
+    let mut parts = Vec::new(env);
+    let mut allocated: i128 = 0;
+    for (i, share) in shares.iter().enumerate() {
+        let part = if i as u32 == shares.len() - 1 {
+            amount - allocated
+        } else {
+            amount * share.share_bps as i128 / 10_000
+        };
+        allocated += part;
+        parts.push_back(part);
+    }
+    parts
+}
+
+/// Transfers a premium from `from` to the policy's risk pool, or across its
+/// co-insurers by share, and credits it to each pool's premium income. The
+/// pools must trust this contract.
+fn pay_premium(env: &Env, premium_token: &Address, from: &Address, policy: &InsurancePolicy, amount: i128) {
+    match get_coinsurance(env, policy.policy_id) {
+        Some(shares) => {
+            let parts = split_by_shares(env, &shares, amount);
+            for (share, part) in shares.iter().zip(parts.iter()) {
+                credit_pool(env, premium_token, from, &share.pool, part);
+            }
+        }
+        None => credit_pool(env, premium_token, from, &policy.risk_pool, amount),
+    }
+
+    let collected: i128 = env.storage().instance().get(&DataKey::CollectedPremiums).unwrap_or(0);
+    env.storage().instance().set(&DataKey::CollectedPremiums, &(collected + amount));
+}
+
+fn credit_pool(env: &Env, premium_token: &Address, from: &Address, pool: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    token::Client::new(env, premium_token).transfer(from, pool, &amount);
+    env.invoke_contract::<()>(
+        pool,
+        &Symbol::new(env, "credit_premium"),
+        (env.current_contract_address(), amount).into_val(env),
+    );
+}
+
+fn require_premium_balance(env: &Env, premium_token: &Address, payer: &Address, amount: i128) {
+    if token::Client::new(env, premium_token).balance(payer) < amount {
+        panic!("Insufficient funds");
+    }
+}
+
+/// Stored status, except that an active policy past its term reads as Expired
+/// even before `expire_policy` persists the transition.
+fn effective_status(env: &Env, policy: &InsurancePolicy) -> PolicyStatus {
+    let expiry = policy.start_time + (policy.duration_days as u64 * 86400);
+    let in_force = policy.status == PolicyStatus::Active || policy.status == PolicyStatus::Renewed;
+    if in_force && env.ledger().timestamp() > expiry {
+        PolicyStatus::Expired
+    } else {
+        policy.status.clone()
+    }
+}
+
+fn get_premium_lender(env: &Env, policy_id: u64) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::PremiumLender(policy_id))
+}
+
+fn require_claim_processor(env: &Env, processor: &Address) {
+    processor.require_auth();
+    let registered: bool = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ClaimProcessor(processor.clone()))
+        .unwrap_or(false);
+    if *processor != get_admin(env) && !registered {
+        panic!("Not a claim processor");
+    }
+}
+
+/// The policy's claim hold, if one is still in force.
+fn active_claim_hold(env: &Env, policy_id: u64) -> Option<ClaimHold> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, ClaimHold>(&DataKey::ClaimHold(policy_id))
+        .filter(|hold| env.ledger().timestamp() < hold.hold_until)
+}
+
+fn record_claim(env: &Env, policy_id: u64, amount: i128) {
+    // claims stay frozen while a fraud hold is in force
+    if active_claim_hold(env, policy_id).is_some() {
+        panic!("Claims on hold");
+    }
+
+    let mut policy = get_policy_inner(env, policy_id);
+    policy.total_claimed += amount;
+
+    if policy.total_claimed > policy.coverage_amount {
+        panic!("Total claimed exceeds coverage amount");
+    }
+
+    set_policy(env, policy_id, &policy);
+
+    // a paid claim resets the no-claims bonus
+    let claim_count: u32 = env.storage().persistent().get(&DataKey::ClaimCount(policy_id)).unwrap_or(0);
+    env.storage().persistent().set(&DataKey::ClaimCount(policy_id), &(claim_count + 1));
+    env.storage().persistent().set(&DataKey::ClaimFreeTerms(policy_id), &0u32);
+    env.storage().persistent().set(&DataKey::TermHadClaim(policy_id), &true);
+
+    // a claim forfeits the rebate accrued this term
+    take_term_rebate(env, policy_id, &policy.holder);
+
+    // each co-insurer contributes its share of the payout
+    if let Some(shares) = get_coinsurance(env, policy_id) {
+        let parts = split_by_shares(env, &shares, amount);
+        let mut updated = Vec::new(env);
+        for (mut share, part) in shares.iter().zip(parts.iter()) {
+            share.claimed += part;
+            env.events().publish(
+                (symbol_short!("policy"), symbol_short!("coclaim")),
+                (policy_id, share.pool.clone(), part),
+            );
+            updated.push_back(share);
+        }
+        env.storage().persistent().set(&DataKey::Coinsurance(policy_id), &updated);
+    }
+}
+
+/// `payer` funds the premium: the holder, or the lender of a financed policy.
+fn create_policy(
+    env: &Env,
+    holder: Address,
+    payer: &Address,
+    coverage_amount: i128,
+    premium_amount: i128,
+    duration_days: u32,
+    policy_type: PolicyType,
+) -> u64 {
+    let mut counter = get_policy_counter(env);
+    counter += 1;
+    env.storage().instance().set(&DataKey::PolicyCounter, &counter);
+
+    let risk_pool: Address = env.storage().instance().get(&DataKey::RiskPool)
+        .unwrap_or_else(|| panic!("Contract not initialized"));
+
+    require_coverage_within_bounds(env, &policy_type, coverage_amount);
+
+    let policy = InsurancePolicy {
+        policy_id: counter,
+        holder: holder.clone(),
+        coverage_amount,
+        premium_amount,
+        start_time: env.ledger().timestamp(),
+        duration_days,
+        policy_type,
+        status: PolicyStatus::Active,
+        risk_pool,
+        total_claimed: 0,
+    };
+
+    set_policy(env, counter, &policy);
+    add_premiums_charged(env, counter, premium_amount);
+    accrue_rebate(env, counter, &holder, premium_amount);
+
+    // Renewals and cancellations keep the policy id, so the index only grows here
+    let mut holder_policies = get_holder_policies(env, &holder);
+    holder_policies.push_back(counter);
+    env.storage()
+        .persistent()
+        .set(&DataKey::HolderPolicies(holder.clone()), &holder_policies);
+
+    // hold the premium until the waiting period ends
+    if let Some(waiting_period) = env.storage().instance().get::<DataKey, u64>(&DataKey::WaitingPeriod) {
+        payer.require_auth();
+        let premium_token: Address = env.storage().instance().get(&DataKey::PremiumToken).unwrap();
+        require_premium_balance(env, &premium_token, payer, premium_amount);
+        token::Client::new(env, &premium_token).transfer(
+            payer,
+            &env.current_contract_address(),
+            &premium_amount,
+        );
+        set_escrow(env, counter, &PremiumEscrow {
+            amount: premium_amount,
+            coverage_starts_at: policy.start_time + waiting_period,
+            status: EscrowStatus::Held,
+        });
+        add_premium_collected(env, counter, premium_amount);
+    } else if let Some(premium_token) = env.storage().instance().get::<DataKey, Address>(&DataKey::PremiumToken) {
+        // without a waiting period the premium goes straight to the insurer
+        payer.require_auth();
+        require_premium_balance(env, &premium_token, payer, premium_amount);
+        pay_premium(env, &premium_token, payer, &policy, premium_amount);
+        add_premium_collected(env, counter, premium_amount);
+    }
+
+    // #412: Enhanced event emission with more details
+    env.events().publish(
+        (symbol_short!("policy"), symbol_short!("issued")),
+        (counter, holder, coverage_amount, premium_amount, duration_days),
+    );
+
+    counter
+}
+
+fn get_manager_capacity_used(env: &Env, manager: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ManagerCapacityUsed(manager.clone()))
+        .unwrap_or(0)
+}
+
+/// Returns the coverage a manager bound for `policy_id` to their capacity.
+fn release_manager_capacity(env: &Env, policy_id: u64) {
+    if let Some((manager, coverage)) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, (Address, i128)>(&DataKey::PolicyManager(policy_id))
+    {
+        env.storage().persistent().remove(&DataKey::PolicyManager(policy_id));
+        let used = get_manager_capacity_used(env, &manager) - coverage;
+        env.storage()
+            .persistent()
+            .set(&DataKey::ManagerCapacityUsed(manager), &used);
+    }
+}
+
+/// Charges a change in a manager-issued policy's coverage to the manager's
+/// capacity and to the amount bound for the policy, so a later release
+/// returns the policy's current coverage.
+fn adjust_manager_capacity(env: &Env, policy_id: u64, delta: i128) {
+    if let Some((manager, coverage)) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, (Address, i128)>(&DataKey::PolicyManager(policy_id))
+    {
+        let used = get_manager_capacity_used(env, &manager) + delta;
+        if delta > 0 {
+            let capacity: i128 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ManagerCapacity(manager.clone()))
+                .unwrap_or(0);
+            if used > capacity {
+                panic!("Capacity exceeded");
+            }
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::ManagerCapacityUsed(manager.clone()), &used);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PolicyManager(policy_id), &(manager, coverage + delta));
+    }
+}
+
+/// Moves an active policy to Cancelled, releasing manager capacity and
+/// refunding a premium still in escrow. `initiator` is the holder or the
+/// issuing manager.
+fn cancel(env: &Env, policy_id: u64, mut policy: InsurancePolicy, initiator: Address) {
+    if policy.status != PolicyStatus::Active && policy.status != PolicyStatus::Renewed {
+        panic!("Policy not active");
+    }
+
+    // #407: Ensure policy hasn't expired before cancellation
+    let now = env.ledger().timestamp();
+    let expiry = policy.start_time + (policy.duration_days as u64 * 86400);
+    if now > expiry {
+        panic!("Policy has already expired");
+    }
+
+    policy.status = PolicyStatus::Cancelled;
+    set_policy(env, policy_id, &policy);
+    release_manager_capacity(env, policy_id);
+    release_zone_exposure(env, policy_id, policy.coverage_amount);
+
+    // refund an escrowed premium if coverage never started
+    if let Some(mut escrow) = get_escrow(env, policy_id) {
+        if escrow.status == EscrowStatus::Held && now < escrow.coverage_starts_at {
+            // a financed premium goes back to the lender that paid it
+            let refund_to = get_premium_lender(env, policy_id).unwrap_or(policy.holder.clone());
+            let premium_token: Address = env.storage().instance().get(&DataKey::PremiumToken).unwrap();
+            token::Client::new(env, &premium_token).transfer(
+                &env.current_contract_address(),
+                &refund_to,
+                &escrow.amount,
+            );
+            escrow.status = EscrowStatus::Refunded;
+            set_escrow(env, policy_id, &escrow);
+            add_premium_collected(env, policy_id, -escrow.amount);
+
+            env.events().publish(
+                (symbol_short!("policy"), symbol_short!("refunded")),
+                (policy_id, refund_to, escrow.amount),
+            );
+        }
+    }
+
+    // #412: Enhanced event emission
+    env.events().publish(
+        (symbol_short!("policy"), symbol_short!("cancelled")),
+        (policy_id, policy.holder, policy.coverage_amount, initiator),
+    );
+}
+
+fn get_zone_exposure(env: &Env, zone: &Symbol) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ZoneExposure(zone.clone()))
+        .unwrap_or(0)
+}
+
+/// Adds `coverage` to a catastrophe zone's outstanding exposure, enforcing
+/// the zone's aggregate limit if one is set.
+fn bind_zone_exposure(env: &Env, zone: &Symbol, coverage: i128) {
+    let exposure = get_zone_exposure(env, zone) + coverage;
+    if let Some(limit) = env.storage().persistent().get::<DataKey, i128>(&DataKey::ZoneLimit(zone.clone())) {
+        if exposure > limit {
+            panic!("Capacity exceeded");
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::ZoneExposure(zone.clone()), &exposure);
+}
+
+fn release_zone_exposure(env: &Env, policy_id: u64, coverage: i128) {
+    if let Some(zone) = env.storage().persistent().get::<DataKey, Symbol>(&DataKey::PolicyZone(policy_id)) {
+        env.storage().persistent().remove(&DataKey::PolicyZone(policy_id));
+        let exposure = get_zone_exposure(env, &zone) - coverage;
+        env.storage()
+            .persistent()
+            .set(&DataKey::ZoneExposure(zone), &exposure);
+    }
+}
+
 // --------------------------------------------------------
 
 #[contract]
@@ -38,13 +611,17 @@ pub struct PolicyContract;
 
 #[contractimpl]
 impl PolicyContract {
-    pub fn initialize(env: Env, admin: Address, risk_pool: Address) {
+    /// `coverage_denom` is the token every coverage, premium and claim amount
+    /// of this contract is expressed in.
+    pub fn initialize(env: Env, admin: Address, risk_pool: Address, coverage_denom: Address) {
         if env.storage().instance().has(&DataKey::Admin) {
             panic!("Already initialized");
         }
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::RiskPool, &risk_pool);
+        env.storage().instance().set(&DataKey::CoverageDenom, &coverage_denom);
         env.storage().instance().set(&DataKey::PolicyCounter, &0u64);
+        upgradeable::init_version(&env, CONTRACT_VERSION);
     }
 
     pub fn issue_policy(
@@ -60,35 +637,156 @@ impl PolicyContract {
         let admin = get_admin(&env);
         admin.require_auth();
 
-        let mut counter = get_policy_counter(&env);
-        counter += 1;
-        env.storage().instance().set(&DataKey::PolicyCounter, &counter);
+        create_policy(&env, holder.clone(), &holder, coverage_amount, premium_amount, duration_days, policy_type)
+    }
 
-        let risk_pool: Address = env.storage().instance().get(&DataKey::RiskPool)
-            .unwrap_or_else(|| panic!("Contract not initialized"));
+    /// Issues a policy whose premium is paid upfront by `lender`, who finances
+    /// it for the holder. The lender may cancel the policy if the holder
+    /// defaults on the loan.
+    pub fn issue_policy_financed(
+        env: Env,
+        holder: Address,
+        lender: Address,
+        coverage_amount: i128,
+        premium_amount: i128,
+        duration_days: u32,
+        policy_type: PolicyType,
+    ) -> u64 {
+        get_admin(&env).require_auth();
 
-        let policy = InsurancePolicy {
-            policy_id: counter,
-            holder: holder.clone(),
+        let policy_id = create_policy(
+            &env,
+            holder,
+            &lender,
             coverage_amount,
             premium_amount,
-            start_time: env.ledger().timestamp(),
             duration_days,
             policy_type,
-            status: PolicyStatus::Active,
-            risk_pool,
-            total_claimed: 0,
-        };
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::PremiumLender(policy_id), &lender);
 
-        set_policy(&env, counter, &policy);
+        // Without premium collection configured, charge the lender in the coverage denomination
+        if !env.storage().instance().has(&DataKey::PremiumToken) {
+            lender.require_auth();
+            let policy = get_policy_inner(&env, policy_id);
+            pay_premium(&env, &get_coverage_denom(&env), &lender, &policy, premium_amount);
+            add_premium_collected(&env, policy_id, premium_amount);
+        }
 
-        // #412: Enhanced event emission with more details
         env.events().publish(
-            (symbol_short!("policy"), symbol_short!("issued")),
-            (counter, holder, coverage_amount, premium_amount, duration_days),
+            (symbol_short!("policy"), symbol_short!("financed")),
+            (policy_id, lender, premium_amount),
         );
 
-        counter
+        policy_id
+    }
+
+    /// Cancels a financed policy after the holder defaults on the premium loan.
+    pub fn mark_financing_default(env: Env, lender: Address, policy_id: u64) {
+        lender.require_auth();
+        if get_premium_lender(&env, policy_id) != Some(lender.clone()) {
+            panic!("Not the policy's lender");
+        }
+        let policy = get_policy_inner(&env, policy_id);
+        cancel(&env, policy_id, policy, lender);
+    }
+
+    /// Issues a policy in catastrophe zone `zone`, counting its coverage
+    /// towards the zone's aggregate limit until it expires or is cancelled.
+    pub fn issue_zoned_policy(
+        env: Env,
+        holder: Address,
+        zone: Symbol,
+        coverage_amount: i128,
+        premium_amount: i128,
+        duration_days: u32,
+        policy_type: PolicyType,
+    ) -> u64 {
+        get_admin(&env).require_auth();
+        bind_zone_exposure(&env, &zone, coverage_amount);
+
+        let policy_id = create_policy(&env, holder.clone(), &holder, coverage_amount, premium_amount, duration_days, policy_type);
+        env.storage().persistent().set(&DataKey::PolicyZone(policy_id), &zone);
+
+        policy_id
+    }
+
+    /// Issues a policy co-insured by several risk pools. Premiums are split
+    /// across the pools and claims are shared by `share_bps`, which must sum
+    /// to 10000.
+    pub fn issue_coinsured_policy(
+        env: Env,
+        holder: Address,
+        coverage_amount: i128,
+        premium_amount: i128,
+        duration_days: u32,
+        policy_type: PolicyType,
+        shares: Vec<(Address, u32)>,
+    ) -> u64 {
+        get_admin(&env).require_auth();
+
+        if shares.is_empty() || shares.len() > MAX_COINSURERS {
+            panic!("Invalid number of co-insurers");
+        }
+        let mut total_bps: u32 = 0;
+        let mut coinsurers: Vec<CoinsuranceShare> = Vec::new(&env);
+        for (pool, share_bps) in shares.iter() {
+            if share_bps == 0 {
+                panic!("Co-insurance share must be positive");
+            }
+            if coinsurers.iter().any(|c| c.pool == pool) {
+                panic!("Duplicate co-insurer");
+            }
+            total_bps += share_bps;
+            coinsurers.push_back(CoinsuranceShare { pool, share_bps, claimed: 0 });
+        }
+        if total_bps != 10_000 {
+            panic!("Co-insurance shares must sum to 10000");
+        }
+
+        // Shares are stored before issuance so a premium collected at issue is split
+        let policy_id = get_policy_counter(&env) + 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Coinsurance(policy_id), &coinsurers);
+
+        create_policy(&env, holder.clone(), &holder, coverage_amount, premium_amount, duration_days, policy_type)
+    }
+
+    /// Issues a policy on behalf of an underwriting manager, counting its
+    /// coverage against the manager's capacity until it expires or is cancelled.
+    pub fn issue_policy_by_manager(
+        env: Env,
+        manager: Address,
+        holder: Address,
+        coverage_amount: i128,
+        premium_amount: i128,
+        duration_days: u32,
+        policy_type: PolicyType,
+    ) -> u64 {
+        manager.require_auth();
+
+        let capacity: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ManagerCapacity(manager.clone()))
+            .expect("Not a policy manager");
+        let used = get_manager_capacity_used(&env, &manager) + coverage_amount;
+        if used > capacity {
+            panic!("Capacity exceeded");
+        }
+
+        let policy_id = create_policy(&env, holder.clone(), &holder, coverage_amount, premium_amount, duration_days, policy_type);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ManagerCapacityUsed(manager.clone()), &used);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PolicyManager(policy_id), &(manager, coverage_amount));
+
+        policy_id
     }
 
     pub fn get_policy(env: Env, policy_id: u64) -> InsurancePolicy {
@@ -107,6 +805,12 @@ impl PolicyContract {
         }
 
         let now = env.ledger().timestamp();
+        if let Some(escrow) = get_escrow(&env, policy_id) {
+            if now < escrow.coverage_starts_at {
+                return false;
+            }
+        }
+
         let expiry = policy.start_time + (policy.duration_days as u64 * 86400);
         now <= expiry
     }
@@ -128,60 +832,551 @@ impl PolicyContract {
 
         policy.duration_days += duration_days;
         policy.status = PolicyStatus::Renewed;
+        env.storage().persistent().set(&DataKey::TermDays(policy_id), &duration_days);
+
+        // reward a claim-free term with a discount on the renewal premium
+        let mut claim_free_terms = get_claim_free_terms(&env, policy_id);
+        if env.storage().persistent().has(&DataKey::TermHadClaim(policy_id)) {
+            env.storage().persistent().remove(&DataKey::TermHadClaim(policy_id));
+        } else {
+            claim_free_terms += 1;
+            env.storage().persistent().set(&DataKey::ClaimFreeTerms(policy_id), &claim_free_terms);
+        }
+        let base_premium: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BasePremium(policy_id))
+            .unwrap_or(policy.premium_amount);
+        env.storage().persistent().set(&DataKey::BasePremium(policy_id), &base_premium);
+        let discount_bps = no_claims_discount_bps(&env, claim_free_terms);
+        policy.premium_amount = base_premium * (10_000 - discount_bps as i128) / 10_000;
+        // reprice by the policy's claims experience
+        policy.premium_amount = policy.premium_amount * experience_factor_bps(&env, &policy) as i128 / 10_000;
+
+        // pay the claim-free term's rebate as a credit on the renewal premium
+        let rebate = take_term_rebate(&env, policy_id, &policy.holder).min(policy.premium_amount);
+        policy.premium_amount -= rebate;
+
+        // collect the renewal premium before counting it as charged
+        if let Some(premium_token) = env.storage().instance().get::<DataKey, Address>(&DataKey::PremiumToken) {
+            require_premium_balance(&env, &premium_token, &policy.holder, policy.premium_amount);
+            pay_premium(&env, &premium_token, &policy.holder, &policy, policy.premium_amount);
+            add_premium_collected(&env, policy_id, policy.premium_amount);
+        }
+        accrue_rebate(&env, policy_id, &policy.holder, policy.premium_amount);
+        add_premiums_charged(&env, policy_id, policy.premium_amount);
 
         set_policy(&env, policy_id, &policy);
 
         // #412: Enhanced event emission
         env.events().publish(
             (symbol_short!("policy"), symbol_short!("renewed")),
-            (policy_id, policy.holder, duration_days),
+            (policy_id, policy.holder, duration_days, policy.premium_amount),
         );
     }
 
     pub fn cancel_policy(env: Env, policy_id: u64) {
+        let policy = get_policy_inner(&env, policy_id);
+        policy.holder.require_auth();
+        let holder = policy.holder.clone();
+        cancel(&env, policy_id, policy, holder);
+    }
+
+    /// Cancels a policy on behalf of the manager that issued it.
+    pub fn cancel_policy_by_manager(env: Env, manager: Address, policy_id: u64) {
+        manager.require_auth();
+        let policy = get_policy_inner(&env, policy_id);
+        let issuer = env
+            .storage()
+            .persistent()
+            .get::<DataKey, (Address, i128)>(&DataKey::PolicyManager(policy_id))
+            .map(|(issuer, _)| issuer);
+        if issuer != Some(manager.clone()) {
+            panic!("Not the policy's manager");
+        }
+        cancel(&env, policy_id, policy, manager);
+    }
+
+    /// Enables premium escrow: premiums of newly issued policies are held by
+    /// this contract for `waiting_period` seconds before coverage starts.
+    pub fn set_premium_escrow(env: Env, premium_token: Address, waiting_period: u64) {
+        get_admin(&env).require_auth();
+        if premium_token != get_coverage_denom(&env) {
+            panic!("Premium token must match coverage denomination");
+        }
+        env.storage().instance().set(&DataKey::PremiumToken, &premium_token);
+        env.storage().instance().set(&DataKey::WaitingPeriod, &waiting_period);
+
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("escrow")),
+            (premium_token, waiting_period),
+        );
+    }
+
+    /// Collects premiums at issuance and pays them straight to the risk pool,
+    /// replacing premium escrow if it was enabled.
+    pub fn set_premium_collection(env: Env, premium_token: Address) {
+        get_admin(&env).require_auth();
+        if premium_token != get_coverage_denom(&env) {
+            panic!("Premium token must match coverage denomination");
+        }
+        env.storage().instance().set(&DataKey::PremiumToken, &premium_token);
+        env.storage().instance().remove(&DataKey::WaitingPeriod);
+
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("premcoll")),
+            premium_token,
+        );
+    }
+
+    /// Releases an escrowed premium to the risk pool once coverage has
+    /// started. Callable by anyone, typically a keeper.
+    pub fn release_premium(env: Env, policy_id: u64) {
+        let policy = get_policy_inner(&env, policy_id);
+        let mut escrow = get_escrow(&env, policy_id).expect("No premium escrow for policy");
+
+        if escrow.status != EscrowStatus::Held {
+            panic!("Premium escrow already settled");
+        }
+        if env.ledger().timestamp() < escrow.coverage_starts_at {
+            panic!("Coverage has not started yet");
+        }
+
+        let premium_token: Address = env.storage().instance().get(&DataKey::PremiumToken).unwrap();
+        pay_premium(&env, &premium_token, &env.current_contract_address(), &policy, escrow.amount);
+        escrow.status = EscrowStatus::Released;
+        set_escrow(&env, policy_id, &escrow);
+
+        env.events().publish(
+            (symbol_short!("policy"), symbol_short!("released")),
+            (policy_id, policy.risk_pool, escrow.amount),
+        );
+    }
+
+    /// Sets the allowed coverage range for policies of `policy_type`.
+    pub fn set_coverage_bounds(env: Env, policy_type: PolicyType, min_coverage: i128, max_coverage: i128) {
+        get_admin(&env).require_auth();
+        if min_coverage <= 0 || min_coverage > max_coverage {
+            panic!("Invalid coverage bounds");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::CoverageBounds(policy_type), &(min_coverage, max_coverage));
+    }
+
+    /// Adds coverage to an active policy mid-term. `additional_premium` is the
+    /// full-term price of the extra coverage; only the share for the remaining
+    /// term is charged. Returns the premium charged.
+    pub fn add_endorsement(env: Env, policy_id: u64, additional_coverage: i128, additional_premium: i128) -> i128 {
+        get_admin(&env).require_auth();
+
+        if additional_coverage <= 0 || additional_premium < 0 {
+            panic!("Invalid endorsement amounts");
+        }
+
+        let mut policy = get_policy_inner(&env, policy_id);
+        if policy.status != PolicyStatus::Active && policy.status != PolicyStatus::Renewed {
+            panic!("Policy not active");
+        }
+
+        let now = env.ledger().timestamp();
+        let (remaining, term) = current_term_remaining(&env, &policy, now);
+
+        let new_coverage = policy.coverage_amount + additional_coverage;
+        require_coverage_within_bounds(&env, &policy.policy_type, new_coverage);
+        adjust_manager_capacity(&env, policy_id, additional_coverage);
+        if let Some(zone) = env.storage().persistent().get::<DataKey, Symbol>(&DataKey::PolicyZone(policy_id)) {
+            bind_zone_exposure(&env, &zone, additional_coverage);
+        }
+
+        let mut endorsements: Vec<Endorsement> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Endorsements(policy_id))
+            .unwrap_or(Vec::new(&env));
+        if endorsements.len() >= MAX_ENDORSEMENTS {
+            panic!("Endorsement limit reached");
+        }
+
+        let premium_charged = additional_premium * remaining as i128 / term as i128;
+
+        if premium_charged > 0 {
+            if let Some(premium_token) = env.storage().instance().get::<DataKey, Address>(&DataKey::PremiumToken) {
+                policy.holder.require_auth();
+                pay_premium(&env, &premium_token, &policy.holder, &policy, premium_charged);
+                add_premium_collected(&env, policy_id, premium_charged);
+            }
+        }
+
+        policy.coverage_amount = new_coverage;
+        policy.premium_amount += premium_charged;
+        set_policy(&env, policy_id, &policy);
+        add_premiums_charged(&env, policy_id, premium_charged);
+
+        // Later renewals price the endorsed coverage at its full-term premium.
+        if let Some(base_premium) = env.storage().persistent().get::<DataKey, i128>(&DataKey::BasePremium(policy_id)) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::BasePremium(policy_id), &(base_premium + additional_premium));
+        }
+
+        endorsements.push_back(Endorsement {
+            additional_coverage,
+            premium_charged,
+            timestamp: now,
+        });
+        env.storage().persistent().set(&DataKey::Endorsements(policy_id), &endorsements);
+
+        env.events().publish(
+            (symbol_short!("policy"), symbol_short!("endorsed")),
+            (policy_id, additional_coverage, premium_charged),
+        );
+
+        premium_charged
+    }
+
+    /// Lowers a policy's coverage mid-term and returns the premium for the
+    /// removed coverage over the rest of the current term: from escrow if the
+    /// premium is still held, otherwise from the risk pool's premium income.
+    /// Nothing is returned unless premiums are collected in a premium token,
+    /// and never more than the premium actually received. A financed premium goes back to the lender. The reduction is recorded
+    /// as a negative endorsement. Returns the premium returned.
+    pub fn reduce_coverage(env: Env, policy_id: u64, new_coverage: i128) -> i128 {
         let mut policy = get_policy_inner(&env, policy_id);
         policy.holder.require_auth();
 
-        // #407: Ensure policy hasn't expired before cancellation
+        if policy.status != PolicyStatus::Active && policy.status != PolicyStatus::Renewed {
+            panic!("Policy not active");
+        }
+        if new_coverage <= 0 || new_coverage >= policy.coverage_amount {
+            panic!("Invalid coverage reduction");
+        }
+        if new_coverage < policy.total_claimed {
+            panic!("Coverage below claimed amount");
+        }
+        if get_coinsurance(&env, policy_id).is_some() {
+            panic!("Co-insured coverage cannot be reduced");
+        }
+        require_coverage_within_bounds(&env, &policy.policy_type, new_coverage);
+
         let now = env.ledger().timestamp();
-        let expiry = policy.start_time + (policy.duration_days as u64 * 86400);
-        if now > expiry {
-            panic!("Policy has already expired");
+        let (remaining, term) = current_term_remaining(&env, &policy, now);
+
+        let mut endorsements: Vec<Endorsement> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Endorsements(policy_id))
+            .unwrap_or(Vec::new(&env));
+        if endorsements.len() >= MAX_ENDORSEMENTS {
+            panic!("Endorsement limit reached");
+        }
+
+        let removed = policy.coverage_amount - new_coverage;
+        let removed_premium = policy.premium_amount * removed / policy.coverage_amount;
+        // Only premium actually collected in the premium token can be returned,
+        // and an escrowed premium only up to what the escrow still holds.
+        let premium_token = env.storage().instance().get::<DataKey, Address>(&DataKey::PremiumToken);
+        let escrow = get_escrow(&env, policy_id);
+        let mut returned = if premium_token.is_some() {
+            (removed_premium * remaining as i128 / term as i128).min(get_premium_collected(&env, policy_id))
+        } else {
+            0
+        };
+        if let Some(escrow) = &escrow {
+            if escrow.status == EscrowStatus::Held {
+                returned = returned.min(escrow.amount);
+            }
+        }
+
+        if returned > 0 {
+            let refund_to = get_premium_lender(&env, policy_id).unwrap_or(policy.holder.clone());
+            match escrow {
+                Some(mut escrow) if escrow.status == EscrowStatus::Held => {
+                    token::Client::new(&env, &premium_token.unwrap()).transfer(
+                        &env.current_contract_address(),
+                        &refund_to,
+                        &returned,
+                    );
+                    escrow.amount -= returned;
+                    set_escrow(&env, policy_id, &escrow);
+                }
+                _ => env.invoke_contract::<()>(
+                    &policy.risk_pool,
+                    &Symbol::new(&env, "refund_premium"),
+                    (env.current_contract_address(), refund_to, returned).into_val(&env),
+                ),
+            }
         }
 
-        policy.status = PolicyStatus::Cancelled;
+        policy.coverage_amount = new_coverage;
+        policy.premium_amount -= returned;
         set_policy(&env, policy_id, &policy);
+        adjust_manager_capacity(&env, policy_id, -removed);
+        add_premiums_charged(&env, policy_id, -returned);
+        add_premium_collected(&env, policy_id, -returned);
+        if let Some(zone) = env.storage().persistent().get::<DataKey, Symbol>(&DataKey::PolicyZone(policy_id)) {
+            let exposure = get_zone_exposure(&env, &zone) - removed;
+            env.storage().persistent().set(&DataKey::ZoneExposure(zone), &exposure);
+        }
+
+        // Later renewals price only the remaining coverage.
+        let base_premium: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BasePremium(policy_id))
+            .unwrap_or(policy.premium_amount + returned);
+        env.storage()
+            .persistent()
+            .set(&DataKey::BasePremium(policy_id), &(base_premium * new_coverage / (new_coverage + removed)));
+
+        endorsements.push_back(Endorsement {
+            additional_coverage: -removed,
+            premium_charged: -returned,
+            timestamp: now,
+        });
+        env.storage().persistent().set(&DataKey::Endorsements(policy_id), &endorsements);
+
+        env.events().publish(
+            (symbol_short!("policy"), symbol_short!("reduced")),
+            (policy_id, removed, returned),
+        );
+
+        returned
+    }
+
+    /// Configures the no-claims discount earned per consecutive claim-free
+    /// term and its cap, both in basis points.
+    pub fn set_no_claims_bonus(env: Env, per_term_bps: u32, max_bps: u32) {
+        get_admin(&env).require_auth();
+        if per_term_bps > 10_000 || max_bps > 10_000 {
+            panic!("Discount cannot exceed 10000 basis points");
+        }
+        env.storage().instance().set(&DataKey::NoClaimsBonus, &(per_term_bps, max_bps));
+    }
+
+    /// Configures experience rating: renewal premiums are scaled by the
+    /// policy's loss ratio over `target_loss_ratio_bps`, clamped between
+    /// `min_factor_bps` and `max_factor_bps` (10000 leaves the premium unchanged).
+    pub fn set_experience_rating(env: Env, target_loss_ratio_bps: u32, min_factor_bps: u32, max_factor_bps: u32) {
+        get_admin(&env).require_auth();
+        if target_loss_ratio_bps == 0 || min_factor_bps == 0 || min_factor_bps > max_factor_bps {
+            panic!("Invalid experience rating");
+        }
+        env.storage().instance().set(
+            &DataKey::ExperienceRating,
+            &(target_loss_ratio_bps, min_factor_bps, max_factor_bps),
+        );
+    }
+
+    /// Sets the total outstanding coverage `manager` may bind through
+    /// `issue_policy_by_manager`.
+    pub fn set_manager_capacity(env: Env, manager: Address, max_coverage: i128) {
+        get_admin(&env).require_auth();
+        if max_coverage < 0 {
+            panic!("Capacity cannot be negative");
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::ManagerCapacity(manager.clone()), &max_coverage);
+
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("capacity")),
+            (manager, max_coverage),
+        );
+    }
+
+    /// Caps the total outstanding coverage of policies in catastrophe zone `zone`.
+    pub fn set_zone_limit(env: Env, zone: Symbol, limit: i128) {
+        get_admin(&env).require_auth();
+        if limit < 0 {
+            panic!("Limit cannot be negative");
+        }
+        env.storage().persistent().set(&DataKey::ZoneLimit(zone.clone()), &limit);
 
-        // #412: Enhanced event emission
         env.events().publish(
-            (symbol_short!("policy"), symbol_short!("cancelled")),
-            (policy_id, policy.holder, policy.coverage_amount),
+            (symbol_short!("admin"), symbol_short!("zonelimit")),
+            (zone, limit),
         );
     }
 
+    /// Sets the share of each premium, in basis points, set aside as a
+    /// rebate that is credited on renewal if the term stays claim-free.
+    pub fn set_premium_rebate(env: Env, rebate_bps: u32) {
+        get_admin(&env).require_auth();
+        if rebate_bps > 10_000 {
+            panic!("Rebate cannot exceed 10000 basis points");
+        }
+        env.storage().instance().set(&DataKey::PremiumRebateBps, &rebate_bps);
+    }
+
     pub fn set_claims_contract(env: Env, claims_contract: Address) {
         get_admin(&env).require_auth();
         env.storage().instance().set(&DataKey::ClaimsContract, &claims_contract);
     }
 
+    /// Configures the price oracle that parametric policies read from.
+    pub fn set_price_oracle(env: Env, config: OracleConfig) {
+        get_admin(&env).require_auth();
+        oracle::set_oracle_config(&env, &config);
+    }
+
+    pub fn set_claim_processor(env: Env, processor: Address, enabled: bool) {
+        get_admin(&env).require_auth();
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimProcessor(processor.clone()), &enabled);
+
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("processor")),
+            (processor, enabled),
+        );
+    }
+
+    /// Freezes claims on a policy until `hold_until` or until the hold is
+    /// released. Coverage stays in force; only claim payouts are blocked.
+    pub fn place_claim_hold(env: Env, processor: Address, policy_id: u64, reason: Symbol, hold_until: u64) {
+        require_claim_processor(&env, &processor);
+        get_policy_inner(&env, policy_id);
+
+        let now = env.ledger().timestamp();
+        if hold_until <= now {
+            panic!("Hold must end in the future");
+        }
+
+        let hold = ClaimHold {
+            processor: processor.clone(),
+            reason: reason.clone(),
+            placed_at: now,
+            hold_until,
+        };
+        env.storage().persistent().set(&DataKey::ClaimHold(policy_id), &hold);
+
+        let mut history: Vec<ClaimHold> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClaimHoldHistory(policy_id))
+            .unwrap_or(Vec::new(&env));
+        if history.len() >= MAX_HOLD_HISTORY {
+            history.remove(0);
+        }
+        history.push_back(hold);
+        env.storage().persistent().set(&DataKey::ClaimHoldHistory(policy_id), &history);
+
+        env.events().publish(
+            (symbol_short!("policy"), symbol_short!("hold")),
+            (policy_id, processor, reason, hold_until),
+        );
+    }
+
+    pub fn release_claim_hold(env: Env, processor: Address, policy_id: u64) {
+        require_claim_processor(&env, &processor);
+        if !env.storage().persistent().has(&DataKey::ClaimHold(policy_id)) {
+            panic!("No claim hold");
+        }
+        env.storage().persistent().remove(&DataKey::ClaimHold(policy_id));
+
+        env.events().publish(
+            (symbol_short!("policy"), symbol_short!("holdrel")),
+            (policy_id, processor),
+        );
+    }
+
     pub fn update_claimed(env: Env, policy_id: u64, amount: i128) {
         let claims_contract: Address = env.storage().instance().get(&DataKey::ClaimsContract)
             .expect("Claims contract not set");
         claims_contract.require_auth();
 
-        let mut policy = get_policy_inner(&env, policy_id);
-        policy.total_claimed += amount;
+        record_claim(&env, policy_id, amount);
+    }
+
+    /// Issues a policy whose coverage is split into per-peril sub-limits.
+    /// The overall coverage is the sum of the sub-limits.
+    pub fn issue_multiperil_policy(
+        env: Env,
+        holder: Address,
+        perils: Vec<(Symbol, i128)>,
+        premium_amount: i128,
+        duration_days: u32,
+        policy_type: PolicyType,
+    ) -> u64 {
+        if perils.is_empty() || perils.len() > MAX_PERILS {
+            panic!("Invalid number of perils");
+        }
 
-        if policy.total_claimed > policy.coverage_amount {
-            panic!("Total claimed exceeds coverage amount");
+        let mut coverage_amount: i128 = 0;
+        let mut names: Vec<Symbol> = Vec::new(&env);
+        for (peril, limit) in perils.iter() {
+            if limit <= 0 {
+                panic!("Peril sub-limit must be positive");
+            }
+            if names.contains(peril.clone()) {
+                panic!("Duplicate peril");
+            }
+            names.push_back(peril);
+            coverage_amount += limit;
         }
 
-        set_policy(&env, policy_id, &policy);
+        let policy_id = Self::issue_policy(
+            env.clone(),
+            holder,
+            coverage_amount,
+            premium_amount,
+            duration_days,
+            policy_type,
+        );
+
+        for (peril, limit) in perils.iter() {
+            env.storage().persistent().set(
+                &DataKey::Peril(policy_id, peril),
+                &PerilCoverage { limit, claimed: 0 },
+            );
+        }
+
+        env.events().publish(
+            (symbol_short!("policy"), symbol_short!("perils")),
+            (policy_id, names),
+        );
+
+        policy_id
+    }
+
+    /// Records a claim payout against a single peril, drawing down only that
+    /// peril's sub-limit. Called by the claims contract.
+    pub fn record_claim_payout_for_peril(env: Env, policy_id: u64, peril: Symbol, amount: i128) {
+        let claims_contract: Address = env.storage().instance().get(&DataKey::ClaimsContract)
+            .expect("Claims contract not set");
+        claims_contract.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        let key = DataKey::Peril(policy_id, peril.clone());
+        let mut coverage: PerilCoverage = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .expect("Peril not covered by policy");
+        if coverage.claimed + amount > coverage.limit {
+            panic!("Peril sub-limit exceeded");
+        }
+        coverage.claimed += amount;
+        env.storage().persistent().set(&key, &coverage);
+
+        record_claim(&env, policy_id, amount);
+
+        env.events().publish(
+            (symbol_short!("policy"), symbol_short!("perilpaid")),
+            (policy_id, peril, amount),
+        );
     }
 
     pub fn expire_policy(env: Env, policy_id: u64) {
         let mut policy = get_policy_inner(&env, policy_id);
 
+        if policy.status != PolicyStatus::Active && policy.status != PolicyStatus::Renewed {
+            panic!("Policy not active");
+        }
+
         let now = env.ledger().timestamp();
         let expiry = policy.start_time + (policy.duration_days as u64 * 86400);
 
@@ -191,6 +1386,8 @@ impl PolicyContract {
 
         policy.status = PolicyStatus::Expired;
         set_policy(&env, policy_id, &policy);
+        release_manager_capacity(&env, policy_id);
+        release_zone_exposure(&env, policy_id, policy.coverage_amount);
 
         // #412: Enhanced event emission
         env.events().publish(
@@ -202,13 +1399,107 @@ impl PolicyContract {
 
 #[contractimpl]
 impl PolicyContract {
-    pub fn get_policy(env: Env, policy_id: u64) -> InsurancePolicy {
-        get_policy_inner(&env, policy_id)
+    pub fn get_policy_status(env: Env, policy_id: u64) -> PolicyStatus {
+        effective_status(&env, &get_policy_inner(&env, policy_id))
     }
 
-    // Alias used by claims contract cross-contract call
-    pub fn get_pol(env: Env, policy_id: u64) -> InsurancePolicy {
-        get_policy_inner(&env, policy_id)
+    pub fn get_coverage_denomination(env: Env) -> Address {
+        get_coverage_denom(&env)
+    }
+
+    pub fn get_premium_escrow(env: Env, policy_id: u64) -> Option<PremiumEscrow> {
+        get_escrow(&env, policy_id)
+    }
+
+    pub fn get_endorsements(env: Env, policy_id: u64) -> Vec<Endorsement> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Endorsements(policy_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_no_claims_discount(env: Env, policy_id: u64) -> u32 {
+        no_claims_discount_bps(&env, get_claim_free_terms(&env, policy_id))
+    }
+
+    pub fn get_peril_remaining(env: Env, policy_id: u64, peril: Symbol) -> i128 {
+        let coverage: PerilCoverage = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Peril(policy_id, peril))
+            .expect("Peril not covered by policy");
+        coverage.limit - coverage.claimed
+    }
+
+    pub fn get_coinsurance_shares(env: Env, policy_id: u64) -> Vec<CoinsuranceShare> {
+        get_coinsurance(&env, policy_id).unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_manager_capacity_used(env: Env, manager: Address) -> i128 {
+        get_manager_capacity_used(&env, &manager)
+    }
+
+    pub fn get_pending_rebate(env: Env, holder: Address) -> i128 {
+        env.storage().persistent().get(&DataKey::PendingRebate(holder)).unwrap_or(0)
+    }
+
+    pub fn get_claim_hold(env: Env, policy_id: u64) -> Option<ClaimHold> {
+        active_claim_hold(&env, policy_id)
+    }
+
+    pub fn get_claim_hold_history(env: Env, policy_id: u64) -> Vec<ClaimHold> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ClaimHoldHistory(policy_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Current validated price for `feed` from the configured oracle.
+    pub fn get_oracle_price(env: Env, feed: Symbol) -> i128 {
+        oracle::get_validated_price(&env, feed)
+    }
+
+    /// Total premium paid out to risk pools so far.
+    pub fn get_collected_premiums(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::CollectedPremiums).unwrap_or(0)
+    }
+
+    pub fn get_premium_collected(env: Env, policy_id: u64) -> i128 {
+        get_premium_collected(&env, policy_id)
+    }
+
+    pub fn get_zone_exposure(env: Env, zone: Symbol) -> i128 {
+        get_zone_exposure(&env, &zone)
+    }
+
+    pub fn get_premium_lender(env: Env, policy_id: u64) -> Option<Address> {
+        get_premium_lender(&env, policy_id)
+    }
+
+    pub fn get_policy_ids_by_holder(env: Env, holder: Address) -> Vec<u64> {
+        get_holder_policies(&env, &holder)
+    }
+
+    /// Returns up to `limit` of a holder's policies (capped at 50) starting at
+    /// index `start`, along with the holder's total policy count.
+    pub fn get_policies_by_holder(env: Env, holder: Address, start: u32, limit: u32) -> (Vec<InsurancePolicy>, u32) {
+        let ids = get_holder_policies(&env, &holder);
+        let end = start.saturating_add(limit.min(MAX_PAGINATION_LIMIT)).min(ids.len());
+
+        let mut page = Vec::new(&env);
+        for i in start..end {
+            page.push_back(get_policy_inner(&env, ids.get_unchecked(i)));
+        }
+        (page, ids.len())
+    }
+
+    /// Claims paid over premiums charged across all terms, in basis points.
+    pub fn get_loss_ratio(env: Env, policy_id: u64) -> u32 {
+        loss_ratio_bps(&env, &get_policy_inner(&env, policy_id))
+    }
+
+    pub fn get_claim_count(env: Env, policy_id: u64) -> u32 {
+        env.storage().persistent().get(&DataKey::ClaimCount(policy_id)).unwrap_or(0)
     }
 
     pub fn get_stats(env: Env) -> u64 {
@@ -219,3 +1510,31 @@ impl PolicyContract {
         Self::update_claimed(env, policy_id, amount)
     }
 }
+
+#[contractimpl]
+impl Upgradeable for PolicyContract {
+    fn contract_version(env: Env) -> u32 {
+        upgradeable::contract_version(&env)
+    }
+
+    fn upgrade(env: Env, new_version: u32) {
+        get_admin(&env).require_auth();
+        upgradeable::upgrade(&env, new_version);
+    }
+
+    fn rollback(env: Env, target_version: u32) {
+        get_admin(&env).require_auth();
+        upgradeable::rollback(&env, target_version);
+    }
+
+    fn version_info(env: Env) -> VersionInfo {
+        upgradeable::version_info(&env)
+    }
+
+    fn version_history(env: Env) -> Vec<VersionTransition> {
+        upgradeable::version_history(&env)
+    }
+}
+
+#[cfg(test)]
+mod tests;
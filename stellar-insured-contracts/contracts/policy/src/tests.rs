@@ -0,0 +1,1077 @@
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, symbol_short, token, vec, Env};
+
+fn setup(env: &Env) -> (PolicyContractClient<'_>, Address) {
+    setup_with_denom(env, &Address::generate(env))
+}
+
+fn setup_with_denom<'a>(env: &'a Env, coverage_denom: &Address) -> (PolicyContractClient<'a>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, PolicyContract);
+    let client = PolicyContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(&admin, &trusted_pool(env, &contract_id), coverage_denom);
+    (client, admin)
+}
+
+/// A mock risk pool that trusts `policy_contract`.
+fn trusted_pool(env: &Env, policy_contract: &Address) -> Address {
+    let pool = MockPoolClient::new(env, &env.register_contract(None, MockPool));
+    pool.register_trusted_contract(policy_contract);
+    pool.address
+}
+
+#[contract]
+struct MockPool;
+
+#[contractimpl]
+impl MockPool {
+    pub fn register_trusted_contract(env: Env, contract: Address) {
+        env.storage().instance().set(&(symbol_short!("trusted"), contract), &true);
+    }
+
+    pub fn credit_premium(env: Env, caller_contract: Address, amount: i128) {
+        require_trusted(&env, &caller_contract);
+        let credited: i128 = env.storage().instance().get(&symbol_short!("credited")).unwrap_or(0);
+        env.storage().instance().set(&symbol_short!("credited"), &(credited + amount));
+    }
+
+    pub fn refund_premium(env: Env, caller_contract: Address, recipient: Address, amount: i128) {
+        require_trusted(&env, &caller_contract);
+        let paid: i128 = env.storage().instance().get(&recipient).unwrap_or(0);
+        env.storage().instance().set(&recipient, &(paid + amount));
+    }
+
+    pub fn credited(env: Env) -> i128 {
+        env.storage().instance().get(&symbol_short!("credited")).unwrap_or(0)
+    }
+
+    pub fn paid(env: Env, recipient: Address) -> i128 {
+        env.storage().instance().get(&recipient).unwrap_or(0)
+    }
+}
+
+fn require_trusted(env: &Env, caller_contract: &Address) {
+    caller_contract.require_auth();
+    if !env.storage().instance().has(&(symbol_short!("trusted"), caller_contract.clone())) {
+        panic!("Not a trusted contract");
+    }
+}
+
+fn setup_with_pool<'a>(env: &'a Env, holder: &Address) -> (PolicyContractClient<'a>, MockPoolClient<'a>) {
+    let (client, pool) = setup_with_untrusted_pool(env, holder);
+    pool.register_trusted_contract(&client.address);
+    (client, pool)
+}
+
+/// Premiums are collected in a token minted to `holder` and paid to the pool.
+fn setup_with_untrusted_pool<'a>(env: &'a Env, holder: &Address) -> (PolicyContractClient<'a>, MockPoolClient<'a>) {
+    env.mock_all_auths();
+    let premium_token = create_token(env, holder, 1_000);
+    let pool = MockPoolClient::new(env, &env.register_contract(None, MockPool));
+    let client = PolicyContractClient::new(env, &env.register_contract(None, PolicyContract));
+    client.initialize(&Address::generate(env), &pool.address, &premium_token);
+    client.set_premium_collection(&premium_token);
+    (client, pool)
+}
+
+fn create_token(env: &Env, holder: &Address, amount: i128) -> Address {
+    env.mock_all_auths();
+    let token = env.register_stellar_asset_contract(Address::generate(env));
+    token::StellarAssetClient::new(env, &token).mint(holder, &amount);
+    token
+}
+
+fn issue(client: &PolicyContractClient<'_>, holder: &Address, coverage: i128, premium: i128) -> u64 {
+    client.issue_policy(holder, &coverage, &premium, &30, &PolicyType::Standard)
+}
+
+#[test]
+fn test_upgrade_surface() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    assert_eq!(client.contract_version(), 1);
+    assert_eq!(client.version_history().len(), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    client.upgrade(&2);
+
+    let info = client.version_info();
+    assert_eq!(info.current_version, 2);
+    assert_eq!(info.previous_version, 1);
+    assert_eq!(info.last_upgraded_at, 500);
+    let history = client.version_history();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().to_version, 2);
+}
+
+#[test]
+fn test_policies_indexed_by_holder() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let a1 = issue(&client, &alice, 10_000, 1_000);
+    let b1 = issue(&client, &bob, 20_000, 2_000);
+    let a2 = issue(&client, &alice, 30_000, 3_000);
+    let a3 = issue(&client, &alice, 40_000, 4_000);
+    client.renew_policy(&a1, &30);
+    client.cancel_policy(&a2);
+
+    assert_eq!(client.get_policy_ids_by_holder(&alice), vec![&env, a1, a2, a3]);
+    assert_eq!(client.get_policy_ids_by_holder(&bob), vec![&env, b1]);
+
+    let (page, total) = client.get_policies_by_holder(&alice, &1, &10);
+    assert_eq!(total, 3);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().policy_id, a2);
+    assert_eq!(page.get(1).unwrap().coverage_amount, 40_000);
+
+    let (page, total) = client.get_policies_by_holder(&bob, &0, &10);
+    assert_eq!((page.len(), total), (1, 1));
+    assert_eq!(page.get(0).unwrap().holder, bob);
+}
+
+#[test]
+#[should_panic(expected = "New version must be greater than current version")]
+fn test_upgrade_rejects_downgrade() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.upgrade(&1);
+}
+
+#[test]
+fn test_rollback_to_previous_version() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.upgrade(&2);
+
+    env.ledger().with_mut(|li| li.timestamp = 900);
+    client.rollback(&1);
+
+    assert_eq!(client.contract_version(), 1);
+    let history = client.version_history();
+    assert_eq!(history.len(), 2);
+    assert!(!history.get(0).unwrap().is_rollback);
+    let rollback = history.get(1).unwrap();
+    assert_eq!((rollback.from_version, rollback.to_version, rollback.timestamp), (2, 1, 900));
+    assert!(rollback.is_rollback);
+    assert_eq!(client.version_info().previous_version, 2);
+}
+
+#[test]
+#[should_panic(expected = "Rollback target not in version history")]
+fn test_rollback_rejects_unknown_version() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.upgrade(&3);
+    client.rollback(&2);
+}
+
+#[test]
+fn test_premium_escrow_released_on_activation() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let premium_token = create_token(&env, &holder, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    let balances = token::Client::new(&env, &premium_token);
+    client.set_premium_escrow(&premium_token, &86_400);
+
+    let policy_id = issue(&client, &holder, 10_000, 400);
+    assert_eq!(balances.balance(&holder), 600);
+    assert_eq!(balances.balance(&client.address), 400);
+    assert!(!client.is_active(&policy_id));
+
+    env.ledger().with_mut(|li| li.timestamp = 86_400);
+    client.release_premium(&policy_id);
+
+    let risk_pool = client.get_policy(&policy_id).risk_pool;
+    assert_eq!(balances.balance(&risk_pool), 400);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(client.get_premium_escrow(&policy_id).unwrap().status, EscrowStatus::Released);
+    assert!(client.is_active(&policy_id));
+}
+
+#[test]
+#[should_panic(expected = "Coverage has not started yet")]
+fn test_premium_escrow_not_released_early() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let premium_token = create_token(&env, &holder, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    client.set_premium_escrow(&premium_token, &86_400);
+
+    let policy_id = issue(&client, &holder, 10_000, 400);
+    client.release_premium(&policy_id);
+}
+
+#[test]
+fn test_coverage_denomination_stored() {
+    let env = Env::default();
+    let denom = Address::generate(&env);
+    let (client, _admin) = setup_with_denom(&env, &denom);
+    assert_eq!(client.get_coverage_denomination(), denom);
+}
+
+#[test]
+#[should_panic(expected = "Premium token must match coverage denomination")]
+fn test_premium_escrow_rejects_foreign_token() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let holder = Address::generate(&env);
+    let other_token = create_token(&env, &holder, 1_000);
+    client.set_premium_escrow(&other_token, &86_400);
+}
+
+#[test]
+fn test_premium_escrow_refunded_on_early_cancel() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let premium_token = create_token(&env, &holder, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    let balances = token::Client::new(&env, &premium_token);
+    client.set_premium_escrow(&premium_token, &86_400);
+
+    let policy_id = issue(&client, &holder, 10_000, 400);
+    client.cancel_policy(&policy_id);
+
+    assert_eq!(balances.balance(&holder), 1_000);
+    assert_eq!(client.get_premium_escrow(&policy_id).unwrap().status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_add_endorsement_charges_pro_rated_premium() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 300);
+
+    // Ten of thirty days have elapsed, so two thirds of the premium is due.
+    env.ledger().with_mut(|li| li.timestamp = 10 * 86_400);
+    let charged = client.add_endorsement(&policy_id, &5_000, &150);
+    assert_eq!(charged, 100);
+
+    let policy = client.get_policy(&policy_id);
+    assert_eq!(policy.coverage_amount, 15_000);
+    assert_eq!(policy.premium_amount, 400);
+    let endorsements = client.get_endorsements(&policy_id);
+    assert_eq!(endorsements.len(), 1);
+    assert_eq!(endorsements.get(0).unwrap().premium_charged, 100);
+}
+
+#[test]
+fn test_add_endorsement_pro_rates_against_renewed_term() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 300);
+    client.renew_policy(&policy_id, &30);
+
+    // Fifteen days remain of the renewed thirty-day term.
+    env.ledger().with_mut(|li| li.timestamp = 45 * 86_400);
+    assert_eq!(client.add_endorsement(&policy_id, &5_000, &300), 150);
+}
+
+#[test]
+#[should_panic(expected = "Coverage amount outside bounds for policy type")]
+fn test_add_endorsement_rejects_coverage_above_bounds() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_coverage_bounds(&PolicyType::Standard, &1_000, &12_000);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 300);
+
+    client.add_endorsement(&policy_id, &5_000, &150);
+}
+
+#[test]
+fn test_no_claims_discount_escalates_across_terms() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_no_claims_bonus(&1_000, &2_500);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+
+    client.renew_policy(&policy_id, &30);
+    assert_eq!(client.get_no_claims_discount(&policy_id), 1_000);
+    assert_eq!(client.get_policy(&policy_id).premium_amount, 900);
+
+    client.renew_policy(&policy_id, &30);
+    client.renew_policy(&policy_id, &30);
+    assert_eq!(client.get_no_claims_discount(&policy_id), 2_500);
+    assert_eq!(client.get_policy(&policy_id).premium_amount, 750);
+}
+
+#[test]
+fn test_no_claims_discount_reset_by_claim() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_no_claims_bonus(&1_000, &2_500);
+    client.set_claims_contract(&Address::generate(&env));
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+
+    client.renew_policy(&policy_id, &30);
+    client.update_claimed(&policy_id, &2_000);
+    assert_eq!(client.get_no_claims_discount(&policy_id), 0);
+    assert_eq!(client.get_claim_count(&policy_id), 1);
+
+    client.renew_policy(&policy_id, &30);
+    assert_eq!(client.get_no_claims_discount(&policy_id), 0);
+    assert_eq!(client.get_policy(&policy_id).premium_amount, 1_000);
+}
+
+#[test]
+fn test_peril_claims_draw_down_only_their_sub_limit() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_claims_contract(&Address::generate(&env));
+    let holder = Address::generate(&env);
+    let perils = vec![
+        &env,
+        (symbol_short!("fire"), 6_000i128),
+        (symbol_short!("flood"), 4_000i128),
+    ];
+    let policy_id = client.issue_multiperil_policy(&holder, &perils, &500, &30, &PolicyType::Standard);
+    assert_eq!(client.get_policy(&policy_id).coverage_amount, 10_000);
+
+    client.record_claim_payout_for_peril(&policy_id, &symbol_short!("fire"), &6_000);
+
+    assert_eq!(client.get_peril_remaining(&policy_id, &symbol_short!("fire")), 0);
+    assert_eq!(client.get_peril_remaining(&policy_id, &symbol_short!("flood")), 4_000);
+    assert_eq!(client.get_policy(&policy_id).total_claimed, 6_000);
+}
+
+#[test]
+#[should_panic(expected = "Peril sub-limit exceeded")]
+fn test_peril_claim_rejected_above_sub_limit() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_claims_contract(&Address::generate(&env));
+    let holder = Address::generate(&env);
+    let perils = vec![
+        &env,
+        (symbol_short!("fire"), 6_000i128),
+        (symbol_short!("flood"), 4_000i128),
+    ];
+    let policy_id = client.issue_multiperil_policy(&holder, &perils, &500, &30, &PolicyType::Standard);
+
+    client.record_claim_payout_for_peril(&policy_id, &symbol_short!("flood"), &4_001);
+}
+
+#[test]
+fn test_premium_rebate_credited_on_claim_free_renewal() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_premium_rebate(&1_000);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+    assert_eq!(client.get_pending_rebate(&holder), 100);
+
+    client.renew_policy(&policy_id, &30);
+    assert_eq!(client.get_policy(&policy_id).premium_amount, 900);
+    // The new term accrues on the premium actually charged
+    assert_eq!(client.get_pending_rebate(&holder), 90);
+}
+
+#[test]
+fn test_premium_rebate_forfeited_by_claim() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_premium_rebate(&1_000);
+    client.set_claims_contract(&Address::generate(&env));
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+
+    client.update_claimed(&policy_id, &500);
+    assert_eq!(client.get_pending_rebate(&holder), 0);
+
+    client.renew_policy(&policy_id, &30);
+    assert_eq!(client.get_policy(&policy_id).premium_amount, 1_000);
+    assert_eq!(client.get_pending_rebate(&holder), 100);
+}
+
+#[test]
+fn test_manager_capacity_released_on_expiry() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let manager = Address::generate(&env);
+    client.set_manager_capacity(&manager, &10_000);
+
+    let holder = Address::generate(&env);
+    let first = client.issue_policy_by_manager(&manager, &holder, &6_000, &300, &30, &PolicyType::Standard);
+    client.issue_policy_by_manager(&manager, &holder, &4_000, &200, &60, &PolicyType::Standard);
+    assert_eq!(client.get_manager_capacity_used(&manager), 10_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 30 * 86_400);
+    client.expire_policy(&first);
+    assert_eq!(client.get_manager_capacity_used(&manager), 4_000);
+
+    client.issue_policy_by_manager(&manager, &holder, &6_000, &300, &30, &PolicyType::Standard);
+    assert_eq!(client.get_manager_capacity_used(&manager), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Capacity exceeded")]
+fn test_manager_capacity_rejects_over_issuance() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let manager = Address::generate(&env);
+    client.set_manager_capacity(&manager, &10_000);
+
+    let holder = Address::generate(&env);
+    client.issue_policy_by_manager(&manager, &holder, &6_000, &300, &30, &PolicyType::Standard);
+    client.issue_policy_by_manager(&manager, &holder, &4_001, &200, &30, &PolicyType::Standard);
+}
+
+#[test]
+fn test_coinsured_policy_splits_premium_and_claims() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let premium_token = create_token(&env, &holder, 1_000);
+    let balances = token::Client::new(&env, &premium_token);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    client.set_premium_escrow(&premium_token, &0);
+    client.set_claims_contract(&Address::generate(&env));
+
+    let lead = trusted_pool(&env, &client.address);
+    let follow = trusted_pool(&env, &client.address);
+    let shares = vec![&env, (lead.clone(), 6_000u32), (follow.clone(), 4_000u32)];
+    let policy_id = client.issue_coinsured_policy(&holder, &10_000, &1_000, &30, &PolicyType::Standard, &shares);
+
+    client.release_premium(&policy_id);
+    assert_eq!(balances.balance(&lead), 600);
+    assert_eq!(balances.balance(&follow), 400);
+    assert_eq!(MockPoolClient::new(&env, &lead).credited(), 600);
+    assert_eq!(MockPoolClient::new(&env, &follow).credited(), 400);
+
+    client.update_claimed(&policy_id, &2_500);
+    let shares = client.get_coinsurance_shares(&policy_id);
+    assert_eq!(shares.get(0).unwrap().claimed, 1_500);
+    assert_eq!(shares.get(1).unwrap().claimed, 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Co-insurance shares must sum to 10000")]
+fn test_coinsured_policy_rejects_partial_shares() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let holder = Address::generate(&env);
+    let shares = vec![&env, (Address::generate(&env), 6_000u32), (Address::generate(&env), 3_000u32)];
+    client.issue_coinsured_policy(&holder, &10_000, &1_000, &30, &PolicyType::Standard, &shares);
+}
+
+#[test]
+#[should_panic(expected = "Claims on hold")]
+fn test_claim_hold_blocks_claims() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_claims_contract(&Address::generate(&env));
+    let processor = Address::generate(&env);
+    client.set_claim_processor(&processor, &true);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+
+    client.place_claim_hold(&processor, &policy_id, &symbol_short!("fraud"), &5_000);
+    // Coverage stays in force while claims are frozen
+    assert!(client.is_active(&policy_id));
+    client.update_claimed(&policy_id, &500);
+}
+
+#[test]
+fn test_released_claim_hold_resumes_claims() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_claims_contract(&Address::generate(&env));
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+
+    client.place_claim_hold(&admin, &policy_id, &symbol_short!("fraud"), &5_000);
+    client.release_claim_hold(&admin, &policy_id);
+    assert_eq!(client.get_claim_hold(&policy_id), None);
+
+    client.update_claimed(&policy_id, &500);
+    assert_eq!(client.get_policy(&policy_id).total_claimed, 500);
+    assert_eq!(client.get_claim_hold_history(&policy_id).len(), 1);
+}
+
+#[test]
+fn test_timed_claim_hold_expires() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.set_claims_contract(&Address::generate(&env));
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+
+    client.place_claim_hold(&admin, &policy_id, &symbol_short!("fraud"), &5_000);
+    assert!(client.get_claim_hold(&policy_id).is_some());
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    assert_eq!(client.get_claim_hold(&policy_id), None);
+    client.update_claimed(&policy_id, &500);
+    assert_eq!(client.get_policy(&policy_id).total_claimed, 500);
+}
+
+#[test]
+#[should_panic(expected = "Not a claim processor")]
+fn test_claim_hold_requires_processor() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+
+    client.place_claim_hold(&Address::generate(&env), &policy_id, &symbol_short!("fraud"), &5_000);
+}
+
+#[test]
+fn test_holder_cancels_policy() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+
+    client.cancel_policy(&policy_id);
+    assert_eq!(client.get_policy(&policy_id).status, PolicyStatus::Cancelled);
+}
+
+#[test]
+fn test_manager_capacity_follows_endorsements() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let manager = Address::generate(&env);
+    client.set_manager_capacity(&manager, &10_000);
+    let holder = Address::generate(&env);
+    let policy_id = client.issue_policy_by_manager(&manager, &holder, &6_000, &300, &30, &PolicyType::Standard);
+
+    client.add_endorsement(&policy_id, &3_000, &150);
+    assert_eq!(client.get_manager_capacity_used(&manager), 9_000);
+    client.reduce_coverage(&policy_id, &5_000);
+    assert_eq!(client.get_manager_capacity_used(&manager), 5_000);
+
+    client.cancel_policy_by_manager(&manager, &policy_id);
+    assert_eq!(client.get_manager_capacity_used(&manager), 0);
+}
+
+#[test]
+#[should_panic(expected = "Capacity exceeded")]
+fn test_endorsement_cannot_exceed_manager_capacity() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let manager = Address::generate(&env);
+    client.set_manager_capacity(&manager, &10_000);
+    let holder = Address::generate(&env);
+    let policy_id = client.issue_policy_by_manager(&manager, &holder, &6_000, &300, &30, &PolicyType::Standard);
+
+    client.add_endorsement(&policy_id, &4_001, &150);
+}
+
+#[test]
+fn test_manager_cancels_issued_policy() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let manager = Address::generate(&env);
+    client.set_manager_capacity(&manager, &10_000);
+    let holder = Address::generate(&env);
+    let policy_id = client.issue_policy_by_manager(&manager, &holder, &6_000, &300, &30, &PolicyType::Standard);
+
+    client.cancel_policy_by_manager(&manager, &policy_id);
+    assert_eq!(client.get_policy(&policy_id).status, PolicyStatus::Cancelled);
+    assert_eq!(client.get_manager_capacity_used(&manager), 0);
+}
+
+#[test]
+#[should_panic(expected = "Not the policy's manager")]
+fn test_unrelated_manager_cannot_cancel() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let manager = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.set_manager_capacity(&manager, &10_000);
+    client.set_manager_capacity(&other, &10_000);
+    let holder = Address::generate(&env);
+    let policy_id = client.issue_policy_by_manager(&manager, &holder, &6_000, &300, &30, &PolicyType::Standard);
+
+    client.cancel_policy_by_manager(&other, &policy_id);
+}
+
+#[test]
+#[should_panic(expected = "Policy not active")]
+fn test_cancel_rejects_cancelled_policy() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+
+    client.cancel_policy(&policy_id);
+    client.cancel_policy(&policy_id);
+}
+
+#[test]
+fn test_financed_policy_premium_paid_by_lender() {
+    let env = Env::default();
+    let lender = Address::generate(&env);
+    let premium_token = create_token(&env, &lender, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    let balances = token::Client::new(&env, &premium_token);
+    let holder = Address::generate(&env);
+
+    let policy_id = client.issue_policy_financed(&holder, &lender, &10_000, &400, &30, &PolicyType::Standard);
+
+    let policy = client.get_policy(&policy_id);
+    assert_eq!(policy.holder, holder);
+    assert_eq!(balances.balance(&lender), 600);
+    assert_eq!(balances.balance(&policy.risk_pool), 400);
+    assert_eq!(client.get_premium_lender(&policy_id), Some(lender));
+}
+
+#[test]
+fn test_financing_default_cancels_policy() {
+    let env = Env::default();
+    let lender = Address::generate(&env);
+    let premium_token = create_token(&env, &lender, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    let balances = token::Client::new(&env, &premium_token);
+    client.set_premium_escrow(&premium_token, &86_400);
+    let holder = Address::generate(&env);
+
+    let policy_id = client.issue_policy_financed(&holder, &lender, &10_000, &400, &30, &PolicyType::Standard);
+    assert_eq!(balances.balance(&client.address), 400);
+
+    client.mark_financing_default(&lender, &policy_id);
+    assert_eq!(client.get_policy(&policy_id).status, PolicyStatus::Cancelled);
+    // The escrowed premium is returned to the lender, not the holder
+    assert_eq!(balances.balance(&lender), 1_000);
+    assert_eq!(balances.balance(&holder), 0);
+}
+
+#[test]
+#[should_panic(expected = "Not the policy's lender")]
+fn test_financing_default_requires_lender() {
+    let env = Env::default();
+    let lender = Address::generate(&env);
+    let premium_token = create_token(&env, &lender, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    let holder = Address::generate(&env);
+    let policy_id = client.issue_policy_financed(&holder, &lender, &10_000, &400, &30, &PolicyType::Standard);
+
+    client.mark_financing_default(&holder, &policy_id);
+}
+
+#[test]
+fn test_policy_status_reads_expired_past_term() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+    assert_eq!(client.get_policy_status(&policy_id), PolicyStatus::Active);
+
+    env.ledger().with_mut(|li| li.timestamp = 30 * 86_400 + 1);
+    assert_eq!(client.get_policy_status(&policy_id), PolicyStatus::Expired);
+    // Reading the status does not persist it
+    assert_eq!(client.get_policy(&policy_id).status, PolicyStatus::Active);
+
+    client.expire_policy(&policy_id);
+    assert_eq!(client.get_policy(&policy_id).status, PolicyStatus::Expired);
+    assert_eq!(client.get_policy_status(&policy_id), PolicyStatus::Expired);
+}
+
+#[test]
+#[should_panic(expected = "Policy not active")]
+fn test_expire_rejects_cancelled_policy() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+    client.cancel_policy(&policy_id);
+
+    env.ledger().with_mut(|li| li.timestamp = 30 * 86_400 + 1);
+    assert_eq!(client.get_policy_status(&policy_id), PolicyStatus::Cancelled);
+    client.expire_policy(&policy_id);
+}
+
+#[test]
+fn test_zone_exposure_fills_to_limit_and_releases() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let zone = symbol_short!("gulf");
+    client.set_zone_limit(&zone, &10_000);
+    let holder = Address::generate(&env);
+
+    let first = client.issue_zoned_policy(&holder, &zone, &6_000, &300, &30, &PolicyType::Standard);
+    client.issue_zoned_policy(&holder, &zone, &4_000, &200, &30, &PolicyType::Standard);
+    assert_eq!(client.get_zone_exposure(&zone), 10_000);
+
+    client.cancel_policy(&first);
+    assert_eq!(client.get_zone_exposure(&zone), 4_000);
+    // Other zones are tracked separately
+    assert_eq!(client.get_zone_exposure(&symbol_short!("pacific")), 0);
+}
+
+#[test]
+#[should_panic(expected = "Capacity exceeded")]
+fn test_zone_limit_rejects_breaching_policy() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let zone = symbol_short!("gulf");
+    client.set_zone_limit(&zone, &10_000);
+    let holder = Address::generate(&env);
+
+    client.issue_zoned_policy(&holder, &zone, &6_000, &300, &30, &PolicyType::Standard);
+    client.issue_zoned_policy(&holder, &zone, &4_001, &200, &30, &PolicyType::Standard);
+}
+
+#[test]
+fn test_direct_premium_collection_pays_risk_pool() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let premium_token = create_token(&env, &holder, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    let balances = token::Client::new(&env, &premium_token);
+    client.set_premium_collection(&premium_token);
+
+    let policy_id = issue(&client, &holder, 10_000, 400);
+
+    let risk_pool = client.get_policy(&policy_id).risk_pool;
+    assert_eq!(balances.balance(&holder), 600);
+    assert_eq!(balances.balance(&risk_pool), 400);
+    assert_eq!(client.get_collected_premiums(), 400);
+    assert!(client.is_active(&policy_id));
+}
+
+#[test]
+fn test_escrowed_premium_counted_when_released() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let premium_token = create_token(&env, &holder, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    client.set_premium_escrow(&premium_token, &86_400);
+
+    let policy_id = issue(&client, &holder, 10_000, 400);
+    assert_eq!(client.get_collected_premiums(), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 86_400);
+    client.release_premium(&policy_id);
+    assert_eq!(client.get_collected_premiums(), 400);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient funds")]
+fn test_direct_premium_collection_rejects_short_balance() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let premium_token = create_token(&env, &holder, 300);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    client.set_premium_collection(&premium_token);
+
+    issue(&client, &holder, 10_000, 400);
+}
+
+#[test]
+fn test_renewal_collects_repriced_premium() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let premium_token = create_token(&env, &holder, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    let balances = token::Client::new(&env, &premium_token);
+    client.set_premium_collection(&premium_token);
+    client.set_no_claims_bonus(&1_000, &2_500);
+    let policy_id = issue(&client, &holder, 10_000, 300);
+
+    // The renewal is charged at the discounted 270, not the original 300
+    client.renew_policy(&policy_id, &30);
+
+    let risk_pool = client.get_policy(&policy_id).risk_pool;
+    assert_eq!(balances.balance(&holder), 430);
+    assert_eq!(balances.balance(&risk_pool), 570);
+    assert_eq!(client.get_collected_premiums(), 570);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient funds")]
+fn test_renewal_rejects_short_balance() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let (client, _pool) = setup_with_pool(&env, &holder);
+    let policy_id = issue(&client, &holder, 10_000, 600);
+
+    client.renew_policy(&policy_id, &30);
+}
+
+#[test]
+fn test_experience_rating_raises_high_loss_renewal() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_claims_contract(&Address::generate(&env));
+    client.set_experience_rating(&6_000, &8_000, &15_000);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+
+    client.update_claimed(&policy_id, &5_000);
+    assert_eq!(client.get_loss_ratio(&policy_id), 50_000);
+
+    // A 500% loss ratio against a 60% target is clamped to the 150% ceiling
+    client.renew_policy(&policy_id, &30);
+    assert_eq!(client.get_policy(&policy_id).premium_amount, 1_500);
+}
+
+#[test]
+fn test_experience_rating_lowers_low_loss_renewal() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_claims_contract(&Address::generate(&env));
+    client.set_experience_rating(&6_000, &8_000, &15_000);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 1_000);
+
+    client.update_claimed(&policy_id, &300);
+    assert_eq!(client.get_loss_ratio(&policy_id), 3_000);
+
+    // A 30% loss ratio against a 60% target halves the premium, floored at 80%
+    client.renew_policy(&policy_id, &30);
+    assert_eq!(client.get_policy(&policy_id).premium_amount, 800);
+}
+
+#[test]
+fn test_reduce_coverage_returns_pro_rated_premium_from_pool() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let (client, pool) = setup_with_pool(&env, &holder);
+    let policy_id = issue(&client, &holder, 10_000, 300);
+
+    // Halving coverage with twenty of thirty days left returns 150 * 2/3
+    env.ledger().with_mut(|li| li.timestamp = 10 * 86_400);
+    assert_eq!(client.reduce_coverage(&policy_id, &5_000), 100);
+
+    let policy = client.get_policy(&policy_id);
+    assert_eq!(policy.coverage_amount, 5_000);
+    assert_eq!(policy.premium_amount, 200);
+    assert_eq!(pool.credited(), 300);
+    assert_eq!(pool.paid(&holder), 100);
+    assert_eq!(client.get_endorsements(&policy_id).get(0).unwrap().additional_coverage, -5_000);
+}
+
+#[test]
+#[should_panic(expected = "Not a trusted contract")]
+fn test_premium_to_untrusted_pool_rejected() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let (client, _pool) = setup_with_untrusted_pool(&env, &holder);
+    issue(&client, &holder, 10_000, 300);
+}
+
+#[test]
+fn test_reduce_coverage_returns_escrowed_premium() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let premium_token = create_token(&env, &holder, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    let balances = token::Client::new(&env, &premium_token);
+    client.set_premium_escrow(&premium_token, &86_400);
+    let policy_id = issue(&client, &holder, 10_000, 400);
+
+    assert_eq!(client.reduce_coverage(&policy_id, &7_500), 100);
+    assert_eq!(balances.balance(&holder), 700);
+    assert_eq!(client.get_premium_escrow(&policy_id).unwrap().amount, 300);
+}
+
+#[test]
+fn test_reduce_coverage_refund_capped_at_escrow() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let premium_token = create_token(&env, &holder, 2_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    let balances = token::Client::new(&env, &premium_token);
+    client.set_premium_escrow(&premium_token, &86_400);
+    let policy_id = issue(&client, &holder, 10_000, 300);
+    client.add_endorsement(&policy_id, &10_000, &900);
+
+    // 1_140 is pro-rated, but only the 300 still escrowed can be returned here
+    assert_eq!(client.reduce_coverage(&policy_id, &1_000), 300);
+    assert_eq!(balances.balance(&holder), 1_100);
+    assert_eq!(client.get_premium_escrow(&policy_id).unwrap().amount, 0);
+    assert_eq!(client.get_premium_collected(&policy_id), 900);
+}
+
+#[test]
+fn test_reduce_coverage_refund_capped_at_collected_premium() {
+    let env = Env::default();
+    let holder = Address::generate(&env);
+    let premium_token = create_token(&env, &holder, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    let policy_id = issue(&client, &holder, 10_000, 300);
+
+    // Collection enabled after issue: the first term's premium was never received
+    client.set_premium_collection(&premium_token);
+    assert_eq!(client.reduce_coverage(&policy_id, &5_000), 0);
+    assert_eq!(client.get_policy(&policy_id).premium_amount, 300);
+}
+
+#[test]
+fn test_reduce_coverage_refunds_financed_premium_to_lender() {
+    let env = Env::default();
+    let lender = Address::generate(&env);
+    let premium_token = create_token(&env, &lender, 1_000);
+    let (client, _admin) = setup_with_denom(&env, &premium_token);
+    let balances = token::Client::new(&env, &premium_token);
+    client.set_premium_escrow(&premium_token, &86_400);
+    let holder = Address::generate(&env);
+    let policy_id = client.issue_policy_financed(&holder, &lender, &10_000, &400, &30, &PolicyType::Standard);
+
+    assert_eq!(client.reduce_coverage(&policy_id, &7_500), 100);
+    assert_eq!(balances.balance(&lender), 700);
+    assert_eq!(balances.balance(&holder), 0);
+}
+
+#[test]
+fn test_reduce_coverage_without_premium_collection_only_lowers_renewal() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 300);
+
+    env.ledger().with_mut(|li| li.timestamp = 10 * 86_400);
+    assert_eq!(client.reduce_coverage(&policy_id, &5_000), 0);
+    assert_eq!(client.get_policy(&policy_id).premium_amount, 300);
+
+    client.renew_policy(&policy_id, &30);
+    assert_eq!(client.get_policy(&policy_id).premium_amount, 150);
+}
+
+#[test]
+#[should_panic(expected = "Coverage below claimed amount")]
+fn test_reduce_coverage_rejects_below_claimed() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_claims_contract(&Address::generate(&env));
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 300);
+    client.update_claimed(&policy_id, &6_000);
+
+    client.reduce_coverage(&policy_id, &5_000);
+}
+
+#[test]
+#[should_panic(expected = "Coverage amount outside bounds for policy type")]
+fn test_reduce_coverage_enforces_minimum() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    client.set_coverage_bounds(&PolicyType::Standard, &5_000, &20_000);
+    let holder = Address::generate(&env);
+    let policy_id = issue(&client, &holder, 10_000, 300);
+
+    client.reduce_coverage(&policy_id, &4_000);
+}
+
+#[test]
+fn test_version_gated_feature() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    env.as_contract(&client.address, || {
+        assert!(upgradeable::feature_enabled(&env, 1));
+        assert!(!upgradeable::feature_enabled(&env, 2));
+    });
+
+    client.upgrade(&2);
+    env.as_contract(&client.address, || {
+        assert!(upgradeable::feature_enabled(&env, 2));
+        assert!(upgradeable::feature_enabled(&env, 1));
+        upgradeable::require_feature(&env, 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Feature requires a newer contract version")]
+fn test_version_gated_feature_rejects_older_version() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    env.as_contract(&client.address, || upgradeable::require_feature(&env, 2));
+}
+
+#[contract]
+struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn submit(env: Env, feed: Symbol, price: i128, timestamp: u64) {
+        let mut submissions: Vec<oracle::PriceSubmission> =
+            env.storage().instance().get(&feed).unwrap_or(Vec::new(&env));
+        submissions.push_back(oracle::PriceSubmission { price, timestamp });
+        env.storage().instance().set(&feed, &submissions);
+    }
+
+    pub fn submissions(env: Env, feed: Symbol) -> Vec<oracle::PriceSubmission> {
+        env.storage().instance().get(&feed).unwrap_or(Vec::new(&env))
+    }
+}
+
+fn setup_with_oracle(env: &Env) -> (PolicyContractClient<'_>, MockOracleClient<'_>) {
+    let (client, _admin) = setup(env);
+    let feed = MockOracleClient::new(env, &env.register_contract(None, MockOracle));
+    client.set_price_oracle(&OracleConfig {
+        oracle: feed.address.clone(),
+        max_staleness: 600,
+        min_submissions: 3,
+        max_deviation_bps: 1_000,
+    });
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    (client, feed)
+}
+
+#[test]
+fn test_oracle_price_is_median_of_fresh_submissions() {
+    let env = Env::default();
+    let (client, feed) = setup_with_oracle(&env);
+    let rain = symbol_short!("rain");
+
+    feed.submit(&rain, &1_050, &9_900);
+    feed.submit(&rain, &1_000, &9_950);
+    feed.submit(&rain, &980, &10_000);
+    // Stale and ignored
+    feed.submit(&rain, &5_000, &1_000);
+
+    assert_eq!(client.get_oracle_price(&rain), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Oracle data stale")]
+fn test_oracle_stale_submissions_rejected() {
+    let env = Env::default();
+    let (client, feed) = setup_with_oracle(&env);
+    let rain = symbol_short!("rain");
+
+    feed.submit(&rain, &1_000, &9_900);
+    feed.submit(&rain, &1_000, &9_900);
+    feed.submit(&rain, &1_000, &9_000);
+
+    client.get_oracle_price(&rain);
+}
+
+#[test]
+#[should_panic(expected = "Oracle outlier detected")]
+fn test_oracle_outlier_rejected() {
+    let env = Env::default();
+    let (client, feed) = setup_with_oracle(&env);
+    let rain = symbol_short!("rain");
+
+    feed.submit(&rain, &1_000, &9_900);
+    feed.submit(&rain, &1_010, &9_900);
+    feed.submit(&rain, &1_500, &9_900);
+
+    client.get_oracle_price(&rain);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient oracle submissions")]
+fn test_oracle_requires_min_submissions() {
+    let env = Env::default();
+    let (client, feed) = setup_with_oracle(&env);
+    let rain = symbol_short!("rain");
+
+    feed.submit(&rain, &1_000, &9_900);
+    feed.submit(&rain, &1_000, &9_900);
+
+    client.get_oracle_price(&rain);
+}
@@ -1,6 +1,11 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Vec};
+
+const MAX_PAGINATION_LIMIT: u32 = 50;
+/// Bounds for the minimum reserve ratio, in basis points (20%–100%).
+const MIN_RESERVE_RATIO_BPS: u32 = 2_000;
+const MAX_RESERVE_RATIO_BPS: u32 = 10_000;
 
 #[contracttype]
 #[derive(Clone)]
@@ -12,6 +17,14 @@ pub enum DataKey {
     AvailableCapital,
     ClaimsPaid,
     ProviderStake(Address),
+    Providers,
+    /// Minimum share of provider capital, in basis points, that must stay
+    /// available after a withdrawal. Unset means no guard.
+    MinReserveRatio,
+    /// Contracts allowed to draw claim payouts via `pay_claim`.
+    TrustedContract(Address),
+    /// Premiums credited by trusted contracts, net of premium refunds.
+    PremiumIncome,
 }
 
 #[contracttype]
@@ -20,6 +33,7 @@ pub struct PoolStats {
     pub total_capital: i128,
     pub available_capital: i128,
     pub total_claims_paid: i128,
+    pub premium_income: i128,
 }
 
 // --- Storage helpers (#378: data access abstraction) ---
@@ -44,6 +58,32 @@ fn get_provider_stake(env: &Env, provider: &Address) -> i128 {
     env.storage().persistent().get(&DataKey::ProviderStake(provider.clone())).unwrap_or(0)
 }
 
+fn get_providers(env: &Env) -> Vec<Address> {
+    env.storage().persistent().get(&DataKey::Providers).unwrap_or(Vec::new(env))
+}
+
+fn get_premium_income(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::PremiumIncome).unwrap_or(0)
+}
+
+fn get_min_reserve_ratio(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::MinReserveRatio).unwrap_or(0)
+}
+
+fn is_trusted_contract(env: &Env, contract: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TrustedContract(contract.clone()))
+        .unwrap_or(false)
+}
+
+fn require_trusted_contract(env: &Env, contract: &Address) {
+    contract.require_auth();
+    if !is_trusted_contract(env, contract) {
+        panic!("Not a trusted contract");
+    }
+}
+
 // --------------------------------------------------------
 
 #[contract]
@@ -84,6 +124,12 @@ impl RiskPoolContract {
         let new_stake = current_stake + amount;
         env.storage().persistent().set(&DataKey::ProviderStake(provider.clone()), &new_stake);
 
+        let mut providers = get_providers(&env);
+        if !providers.contains(provider.clone()) {
+            providers.push_back(provider.clone());
+            env.storage().persistent().set(&DataKey::Providers, &providers);
+        }
+
         let new_total = get_total_capital(&env) + amount;
         let new_available = get_available_capital(&env) + amount;
         env.storage().instance().set(&DataKey::TotalCapital, &new_total);
@@ -109,6 +155,15 @@ impl RiskPoolContract {
             panic!("Insufficient available capital in pool");
         }
 
+        // Payouts draw down available capital but not provider stakes, so
+        // withdrawals must leave enough of what remains staked on hand.
+        let new_total = get_total_capital(&env) - amount;
+        let new_available = avail - amount;
+        let min_ratio = get_min_reserve_ratio(&env) as i128;
+        if new_total > 0 && new_available * 10_000 < new_total * min_ratio {
+            panic!("Withdrawal breaches minimum reserve ratio");
+        }
+
         let token: Address = env.storage().instance().get(&DataKey::Token)
             .unwrap_or_else(|| panic!("Contract not initialized"));
         let client = soroban_sdk::token::Client::new(&env, &token);
@@ -116,9 +171,15 @@ impl RiskPoolContract {
 
         let new_stake = stake - amount;
         env.storage().persistent().set(&DataKey::ProviderStake(provider.clone()), &new_stake);
-        
-        let new_total = get_total_capital(&env) - amount;
-        let new_available = avail - amount;
+
+        if new_stake == 0 {
+            let mut providers = get_providers(&env);
+            if let Some(index) = providers.first_index_of(provider.clone()) {
+                providers.remove(index);
+                env.storage().persistent().set(&DataKey::Providers, &providers);
+            }
+        }
+
         env.storage().instance().set(&DataKey::TotalCapital, &new_total);
         env.storage().instance().set(&DataKey::AvailableCapital, &new_available);
 
@@ -129,30 +190,97 @@ impl RiskPoolContract {
         );
     }
 
+    pub fn set_min_reserve_ratio(env: Env, ratio_bps: u32) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        if !(MIN_RESERVE_RATIO_BPS..=MAX_RESERVE_RATIO_BPS).contains(&ratio_bps) {
+            panic!("Invalid reserve ratio: must be between 20% and 100%");
+        }
+        env.storage().instance().set(&DataKey::MinReserveRatio, &ratio_bps);
+    }
+
     pub fn payout_claim(env: Env, recipient: Address, amount: i128) {
         let admin = get_admin(&env);
         admin.require_auth();
 
-        // #410: Verify available capital before payout
+        pay_out(&env, recipient, amount);
+    }
+
+    pub fn register_trusted_contract(env: Env, contract: Address) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::TrustedContract(contract), &true);
+    }
+
+    pub fn unregister_trusted_contract(env: Env, contract: Address) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::TrustedContract(contract));
+    }
+
+    /// Pays a claim on behalf of a registered claims or policy contract.
+    /// Callers pass `env.current_contract_address()` as `caller_contract`;
+    /// invoking the pool directly satisfies that address's auth.
+    pub fn pay_claim(env: Env, caller_contract: Address, claimant: Address, amount: i128) {
+        require_trusted_contract(&env, &caller_contract);
+
+        pay_out(&env, claimant, amount);
+    }
+
+    /// Records a premium a trusted contract has already transferred to the
+    /// pool, making it available for payouts.
+    pub fn credit_premium(env: Env, caller_contract: Address, amount: i128) {
+        require_trusted_contract(&env, &caller_contract);
+        if amount <= 0 {
+            panic!("Premium must be positive");
+        }
+
+        let new_available = get_available_capital(&env) + amount;
+        env.storage().instance().set(&DataKey::AvailableCapital, &new_available);
+        let income = get_premium_income(&env) + amount;
+        env.storage().instance().set(&DataKey::PremiumIncome, &income);
+
+        env.events().publish(
+            (symbol_short!("pool"), symbol_short!("premium")),
+            (caller_contract, amount, new_available),
+        );
+    }
+
+    /// Returns part of a premium credited by a trusted contract. Refunds come
+    /// out of premium income, never provider capital, and are not counted
+    /// as claims paid.
+    pub fn refund_premium(env: Env, caller_contract: Address, recipient: Address, amount: i128) {
+        require_trusted_contract(&env, &caller_contract);
+        if amount <= 0 {
+            panic!("Refund must be positive");
+        }
+
+        let income = get_premium_income(&env);
         let avail = get_available_capital(&env);
-        if avail < amount {
-            panic!("Insufficient pool funds for payout");
+        if amount > income || amount > avail {
+            panic!("Refund exceeds premium income");
         }
 
-        let token: Address = env.storage().instance().get(&DataKey::Token)
-            .unwrap_or_else(|| panic!("Contract not initialized"));
-        let client = soroban_sdk::token::Client::new(&env, &token);
-        client.transfer(&env.current_contract_address(), &recipient, &amount);
+        let token = get_token(&env);
+        soroban_sdk::token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &amount,
+        );
 
         let new_available = avail - amount;
         env.storage().instance().set(&DataKey::AvailableCapital, &new_available);
+        env.storage().instance().set(&DataKey::PremiumIncome, &(income - amount));
 
-        let paid = env.storage().instance().get(&DataKey::ClaimsPaid).unwrap_or(0);
-        env.storage().instance().set(&DataKey::ClaimsPaid, &(paid + amount));
-
-        // #412: Enhanced event emission with recipient info
         env.events().publish(
-            (symbol_short!("pool"), symbol_short!("payout")),
+            (symbol_short!("pool"), symbol_short!("refund")),
             (recipient, amount, new_available),
         );
     }
@@ -165,10 +293,64 @@ impl RiskPoolContract {
             total_capital: get_total_capital(&env),
             available_capital: get_available_capital(&env),
             total_claims_paid: env.storage().instance().get(&DataKey::ClaimsPaid).unwrap_or(0),
+            premium_income: get_premium_income(&env),
         }
     }
 
+    pub fn get_min_reserve_ratio(env: Env) -> u32 {
+        get_min_reserve_ratio(&env)
+    }
+
+    pub fn is_trusted_contract(env: Env, contract: Address) -> bool {
+        is_trusted_contract(&env, &contract)
+    }
+
     pub fn get_provider_info(env: Env, provider: Address) -> i128 {
         get_provider_stake(&env, &provider)
     }
+
+    /// Returns every provider with a non-zero stake.
+    pub fn get_all_providers(env: Env) -> Vec<Address> {
+        get_providers(&env)
+    }
+
+    /// Returns up to `limit` providers (capped at 50) starting at index `start`.
+    pub fn get_providers_paginated(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        let providers = get_providers(&env);
+        let end = start.saturating_add(limit.min(MAX_PAGINATION_LIMIT)).min(providers.len());
+
+        let mut page = Vec::new(&env);
+        for i in start..end {
+            page.push_back(providers.get_unchecked(i));
+        }
+        page
+    }
+}
+
+fn pay_out(env: &Env, recipient: Address, amount: i128) {
+    // #410: Verify available capital before payout
+    let avail = get_available_capital(env);
+    if avail < amount {
+        panic!("Insufficient pool funds for payout");
+    }
+
+    let token: Address = env.storage().instance().get(&DataKey::Token)
+        .unwrap_or_else(|| panic!("Contract not initialized"));
+    let client = soroban_sdk::token::Client::new(env, &token);
+    client.transfer(&env.current_contract_address(), &recipient, &amount);
+
+    let new_available = avail - amount;
+    env.storage().instance().set(&DataKey::AvailableCapital, &new_available);
+
+    let paid = env.storage().instance().get(&DataKey::ClaimsPaid).unwrap_or(0);
+    env.storage().instance().set(&DataKey::ClaimsPaid, &(paid + amount));
+
+    // #412: Enhanced event emission with recipient info
+    env.events().publish(
+        (symbol_short!("pool"), symbol_short!("payout")),
+        (recipient, amount, new_available),
+    );
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,159 @@
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, vec, Env};
+
+fn setup(env: &Env) -> (RiskPoolContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RiskPoolContract);
+    let client = RiskPoolContractClient::new(env, &contract_id);
+
+    let token = env.register_stellar_asset_contract(Address::generate(env));
+    client.initialize(&Address::generate(env), &token, &100);
+    (client, token)
+}
+
+fn funded_provider(env: &Env, token: &Address, amount: i128) -> Address {
+    let provider = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&provider, &amount);
+    provider
+}
+
+#[test]
+fn test_provider_index_tracks_add_remove_and_readd() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let first = funded_provider(&env, &token, 1_000);
+    let second = funded_provider(&env, &token, 1_000);
+
+    client.deposit_liquidity(&first, &500);
+    client.deposit_liquidity(&second, &500);
+    client.deposit_liquidity(&first, &200);
+    assert_eq!(client.get_all_providers(), vec![&env, first.clone(), second.clone()]);
+
+    // A partial withdrawal keeps the provider indexed
+    client.withdraw_liquidity(&first, &200);
+    assert_eq!(client.get_all_providers().len(), 2);
+
+    client.withdraw_liquidity(&first, &500);
+    assert_eq!(client.get_all_providers(), vec![&env, second.clone()]);
+
+    client.deposit_liquidity(&first, &300);
+    assert_eq!(client.get_all_providers(), vec![&env, second.clone(), first.clone()]);
+
+    assert_eq!(client.get_providers_paginated(&1, &10), vec![&env, first.clone()]);
+    assert_eq!(client.get_providers_paginated(&5, &10).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Amount below minimum stake")]
+fn test_deposit_below_min_stake_rejected() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let provider = funded_provider(&env, &token, 1_000);
+    client.deposit_liquidity(&provider, &99);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient stake")]
+fn test_withdraw_over_balance_rejected() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let provider = funded_provider(&env, &token, 1_000);
+    client.deposit_liquidity(&provider, &500);
+    client.withdraw_liquidity(&provider, &501);
+}
+
+#[test]
+fn test_withdrawal_guarded_by_reserve_ratio() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let first = funded_provider(&env, &token, 1_000);
+    let second = funded_provider(&env, &token, 1_000);
+    client.deposit_liquidity(&first, &1_000);
+    client.deposit_liquidity(&second, &1_000);
+    client.set_min_reserve_ratio(&5_000);
+
+    // 2_000 staked, 800 left after payouts
+    client.payout_claim(&Address::generate(&env), &1_200);
+
+    // 500 / 1_700 ≈ 29% breaches a 50% floor
+    let breach = client.try_withdraw_liquidity(&first, &300);
+    assert!(breach.is_err());
+
+    client.set_min_reserve_ratio(&2_000);
+    // 500 / 1_700 ≈ 29% clears a 20% floor
+    client.withdraw_liquidity(&first, &300);
+    assert_eq!(client.get_pool_stats().available_capital, 500);
+}
+
+#[test]
+#[should_panic(expected = "Invalid reserve ratio")]
+fn test_reserve_ratio_bounds() {
+    let env = Env::default();
+    let (client, _token) = setup(&env);
+    client.set_min_reserve_ratio(&1_999);
+}
+
+#[test]
+fn test_pay_claim_requires_trusted_caller() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let provider = funded_provider(&env, &token, 1_000);
+    client.deposit_liquidity(&provider, &1_000);
+
+    let claims_contract = Address::generate(&env);
+    let claimant = Address::generate(&env);
+    client.register_trusted_contract(&claims_contract);
+    client.pay_claim(&claims_contract, &claimant, &300);
+    assert_eq!(token::Client::new(&env, &token).balance(&claimant), 300);
+
+    client.unregister_trusted_contract(&claims_contract);
+    assert!(!client.is_trusted_contract(&claims_contract));
+    assert!(client.try_pay_claim(&claims_contract, &claimant, &300).is_err());
+    assert_eq!(client.get_pool_stats().available_capital, 700);
+}
+
+#[test]
+fn test_premium_refund_drawn_from_premium_income() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let provider = funded_provider(&env, &token, 1_000);
+    client.deposit_liquidity(&provider, &1_000);
+
+    let policy_contract = funded_provider(&env, &token, 400);
+    client.register_trusted_contract(&policy_contract);
+    token::Client::new(&env, &token).transfer(&policy_contract, &client.address, &400);
+    client.credit_premium(&policy_contract, &400);
+    assert_eq!(client.get_pool_stats().available_capital, 1_400);
+
+    let holder = Address::generate(&env);
+    client.refund_premium(&policy_contract, &holder, &150);
+    assert_eq!(token::Client::new(&env, &token).balance(&holder), 150);
+
+    let stats = client.get_pool_stats();
+    assert_eq!(stats.available_capital, 1_250);
+    assert_eq!(stats.premium_income, 250);
+    assert_eq!(stats.total_claims_paid, 0);
+    assert_eq!(stats.total_capital, 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Refund exceeds premium income")]
+fn test_premium_refund_cannot_touch_provider_capital() {
+    let env = Env::default();
+    let (client, token) = setup(&env);
+    let provider = funded_provider(&env, &token, 1_000);
+    client.deposit_liquidity(&provider, &1_000);
+
+    let policy_contract = Address::generate(&env);
+    client.register_trusted_contract(&policy_contract);
+    client.refund_premium(&policy_contract, &Address::generate(&env), &1);
+}
+
+#[test]
+#[should_panic(expected = "Not a trusted contract")]
+fn test_credit_premium_requires_trusted_caller() {
+    let env = Env::default();
+    let (client, _token) = setup(&env);
+    client.credit_premium(&Address::generate(&env), &100);
+}